@@ -99,6 +99,12 @@ public abstract class ErrorMessage extends com.vaticle.typedb.common.exception.E
                 new Server(36, "An error occurred while logging server connection info.");
         public static final Server USER_MANAGEMENT_NOT_AVAILABLE =
                 new Server(37, "User management is only available in TypeDB Cloud.");
+        public static final Server FAILED_TO_LOAD_TLS_CONFIGURATION =
+                new Server(38, "Failed to load the TLS certificates or private key configured for encrypted connections:\n'%s'");
+        public static final Server TRANSACTION_LIMIT_REACHED =
+                new Server(39, "The server has reached its limit of '%s' concurrently open transactions. Close an open transaction and retry.");
+        public static final Server SESSION_TRANSACTION_LIMIT_REACHED =
+                new Server(40, "The session has reached its limit of '%s' concurrently open transactions. Close an open transaction in this session and retry.");
 
         private static final String codePrefix = "SRV";
         private static final String messagePrefix = "Invalid Server Operation";
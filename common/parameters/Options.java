@@ -35,6 +35,11 @@ public abstract class Options<PARENT extends Options<?, ?>, SELF extends Options
     public static final boolean DEFAULT_QUERY_READ_PREFETCH = true;
     public static final boolean DEFAULT_QUERY_WRITE_PREFETCH = false;
     public static final boolean DEFAULT_READ_ANY_REPLICA = false;
+    public static final boolean DEFAULT_SLOW_QUERY_LOG = false;
+    public static final long DEFAULT_SLOW_QUERY_THRESHOLD_MILLIS = SECONDS.toMillis(5);
+    public static final double DEFAULT_SLOW_QUERY_SAMPLING_RATE = 1.0;
+    public static final long DEFAULT_STORAGE_READAHEAD_SIZE = 0;
+    public static final long DEFAULT_STORAGE_COMPACTION_RATE_LIMIT = 0;
 
     private PARENT parent;
     private Boolean infer = null;
@@ -47,12 +52,17 @@ public abstract class Options<PARENT extends Options<?, ?>, SELF extends Options
     private Long transactionTimeoutMillis = null;
     private Long schemaLockAcquireTimeoutMillis = null;
     private Boolean readAnyReplica = null;
+    private Boolean slowQueryLog = null;
+    private Long slowQueryThresholdMillis = null;
+    private Double slowQuerySamplingRate = null;
     protected Boolean prefetch = null;
     protected Path typeDBDir = null;
     protected Path dataDir = null;
     protected Path reasonerDebuggerDir = null;
     protected Long storageDataCacheSize = null;
     protected Long storageIndexCacheSize = null;
+    protected Long storageReadaheadSize = null;
+    protected Long storageCompactionRateLimit = null;
 
     abstract SELF getThis();
 
@@ -171,6 +181,39 @@ public abstract class Options<PARENT extends Options<?, ?>, SELF extends Options
         return getThis();
     }
 
+    public boolean slowQueryLog() {
+        if (slowQueryLog != null) return slowQueryLog;
+        else if (parent != null) return parent.slowQueryLog();
+        else return DEFAULT_SLOW_QUERY_LOG;
+    }
+
+    public SELF slowQueryLog(boolean slowQueryLog) {
+        this.slowQueryLog = slowQueryLog;
+        return getThis();
+    }
+
+    public long slowQueryThresholdMillis() {
+        if (slowQueryThresholdMillis != null) return slowQueryThresholdMillis;
+        else if (parent != null) return parent.slowQueryThresholdMillis();
+        else return DEFAULT_SLOW_QUERY_THRESHOLD_MILLIS;
+    }
+
+    public SELF slowQueryThresholdMillis(long thresholdMillis) {
+        this.slowQueryThresholdMillis = thresholdMillis;
+        return getThis();
+    }
+
+    public double slowQuerySamplingRate() {
+        if (slowQuerySamplingRate != null) return slowQuerySamplingRate;
+        else if (parent != null) return parent.slowQuerySamplingRate();
+        else return DEFAULT_SLOW_QUERY_SAMPLING_RATE;
+    }
+
+    public SELF slowQuerySamplingRate(double samplingRate) {
+        this.slowQuerySamplingRate = samplingRate;
+        return getThis();
+    }
+
     public Path typeDBDir() {
         if (typeDBDir != null) return typeDBDir;
         else if (parent != null) return parent.typeDBDir();
@@ -201,6 +244,26 @@ public abstract class Options<PARENT extends Options<?, ?>, SELF extends Options
         else throw TypeDBException.of(ILLEGAL_STATE);
     }
 
+    /**
+     * @return the readahead size, in bytes, used by storage iterators that scan across prefixes,
+     * or 0 to let the storage engine tune readahead automatically
+     */
+    public long storageReadaheadSize() {
+        if (storageReadaheadSize != null) return storageReadaheadSize;
+        else if (parent != null) return parent.storageReadaheadSize();
+        else return DEFAULT_STORAGE_READAHEAD_SIZE;
+    }
+
+    /**
+     * @return the maximum rate, in bytes per second, at which storage flushes and compactions may write to disk,
+     * or 0 to leave background compaction unthrottled
+     */
+    public long storageCompactionRateLimit() {
+        if (storageCompactionRateLimit != null) return storageCompactionRateLimit;
+        else if (parent != null) return parent.storageCompactionRateLimit();
+        else return DEFAULT_STORAGE_COMPACTION_RATE_LIMIT;
+    }
+
     public static class Database extends Options<Options<?, ?>, Database> {
 
         @Override
@@ -236,6 +299,16 @@ public abstract class Options<PARENT extends Options<?, ?>, SELF extends Options
             this.storageIndexCacheSize = size;
             return this;
         }
+
+        public Database storageReadaheadSize(long size) {
+            this.storageReadaheadSize = size;
+            return this;
+        }
+
+        public Database storageCompactionRateLimit(long bytesPerSecond) {
+            this.storageCompactionRateLimit = bytesPerSecond;
+            return this;
+        }
     }
 
     public static class Session extends Options<Database, Session> {
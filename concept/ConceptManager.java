@@ -10,12 +10,15 @@ import com.vaticle.typedb.common.collection.Either;
 import com.vaticle.typedb.core.common.collection.ByteArray;
 import com.vaticle.typedb.core.common.exception.ErrorMessage;
 import com.vaticle.typedb.core.common.exception.TypeDBException;
+import com.vaticle.typedb.core.common.iterator.sorted.SortedIterator.Forwardable;
 import com.vaticle.typedb.core.common.parameters.Label;
+import com.vaticle.typedb.core.common.parameters.Order;
 import com.vaticle.typedb.core.concept.answer.ConceptMap;
 import com.vaticle.typedb.core.concept.thing.Attribute;
 import com.vaticle.typedb.core.concept.thing.Entity;
 import com.vaticle.typedb.core.concept.thing.Relation;
 import com.vaticle.typedb.core.concept.thing.Thing;
+import com.vaticle.typedb.core.concept.thing.impl.RelationImpl;
 import com.vaticle.typedb.core.concept.thing.impl.ThingImpl;
 import com.vaticle.typedb.core.concept.type.AttributeType;
 import com.vaticle.typedb.core.concept.type.EntityType;
@@ -38,9 +41,11 @@ import com.vaticle.typedb.core.graph.vertex.TypeVertex;
 import com.vaticle.typedb.core.traversal.common.Identifier.Variable.Retrievable;
 import com.vaticle.typedb.core.traversal.common.VertexMap;
 
+import javax.annotation.Nullable;
 import java.util.HashMap;
 import java.util.List;
 import java.util.Map;
+import java.util.Optional;
 import java.util.concurrent.ConcurrentHashMap;
 import java.util.concurrent.ConcurrentMap;
 import java.util.function.BiFunction;
@@ -48,6 +53,7 @@ import java.util.function.Function;
 import java.util.stream.Collectors;
 
 import static com.vaticle.typedb.common.collection.Collections.list;
+import static com.vaticle.typedb.core.common.exception.ErrorMessage.Internal.ILLEGAL_ARGUMENT;
 import static com.vaticle.typedb.core.common.exception.ErrorMessage.Internal.ILLEGAL_STATE;
 import static com.vaticle.typedb.core.common.exception.ErrorMessage.Internal.UNRECOGNISED_VALUE;
 import static com.vaticle.typedb.core.common.exception.ErrorMessage.Server.BAD_VALUE_TYPE;
@@ -368,6 +374,28 @@ public final class ConceptManager {
         }
     }
 
+    /**
+     * Verifies the optimised role player edges of up to {@code batchSize} instances of the given relation type, and
+     * its subtypes, against their role vertices, and repairs any relation found to be inconsistent. Relations are
+     * visited in IID order, starting after {@code resumeAfter} if given, so that a large relation type can be repaired
+     * over several transactions, committing each batch and resuming from {@link RolePlayerIndexRepair#resumeAfter()}.
+     * Inconsistent relations are collected before any repair, so the scan is not affected by the edges it writes.
+     */
+    public RolePlayerIndexRepair repairRolePlayerIndex(RelationType relationType, @Nullable ByteArray resumeAfter, int batchSize) {
+        if (batchSize <= 0) throw TypeDBException.of(ILLEGAL_ARGUMENT);
+        Forwardable<RelationImpl, Order.Asc> relations = ((RelationTypeImpl) relationType).getInstances();
+        if (resumeAfter != null) {
+            relations.forward(RelationImpl.of(this, graphMgr.data().convertToReadable(VertexIID.Thing.of(resumeAfter))));
+            if (relations.hasNext() && relations.peek().getIID().equals(resumeAfter)) relations.next();
+        }
+        List<RelationImpl> batch = relations.limit(batchSize).toList();
+        List<RelationImpl> inconsistent = iterate(batch).filter(relation -> !relation.isRolePlayerIndexConsistent()).toList();
+        List<ByteArray> orphanedRoles = iterate(batch).flatMap(relation -> iterate(relation.getOrphanedRoles())).toList();
+        inconsistent.forEach(RelationImpl::repairRolePlayerIndex);
+        ByteArray last = batch.size() < batchSize ? null : batch.get(batch.size() - 1).getIID();
+        return new RolePlayerIndexRepair(inconsistent.size(), orphanedRoles, last);
+    }
+
     public void validateThings() {
         List<List<Thing>> lists = graphMgr.data().writeVertices().filter(
                 v -> v.existence().equals(STORED) && v.isModified() && !v.encoding().equals(ROLE)
@@ -404,4 +432,38 @@ public final class ConceptManager {
     public TypeDBException exception(Exception exception) {
         return graphMgr.exception(exception);
     }
+
+    public static class RolePlayerIndexRepair {
+
+        private final long repaired;
+        private final List<ByteArray> orphanedRoles;
+        private final ByteArray resumeAfter;
+
+        private RolePlayerIndexRepair(long repaired, List<ByteArray> orphanedRoles, @Nullable ByteArray resumeAfter) {
+            this.repaired = repaired;
+            this.orphanedRoles = orphanedRoles;
+            this.resumeAfter = resumeAfter;
+        }
+
+        /**
+         * @return the number of relations repaired in this batch
+         */
+        public long repaired() {
+            return repaired;
+        }
+
+        /**
+         * @return the IIDs of role vertices in this batch that have no player, which cannot be repaired
+         */
+        public List<ByteArray> orphanedRoles() {
+            return orphanedRoles;
+        }
+
+        /**
+         * @return the IID of the last relation visited, to resume from, or empty if the relation type is exhausted
+         */
+        public Optional<ByteArray> resumeAfter() {
+            return Optional.ofNullable(resumeAfter);
+        }
+    }
 }
@@ -11,6 +11,7 @@ import com.vaticle.typedb.core.common.iterator.FunctionalIterator;
 import com.vaticle.typedb.core.common.parameters.Concept.Existence;
 import com.vaticle.typedb.core.concept.Concept;
 import com.vaticle.typedb.core.concept.type.AttributeType;
+import com.vaticle.typedb.core.concept.type.RelationType;
 import com.vaticle.typedb.core.concept.type.RoleType;
 import com.vaticle.typedb.core.concept.type.ThingType;
 import com.vaticle.typeql.lang.common.TypeQLToken.Annotation;
@@ -155,6 +156,17 @@ public interface Thing extends Concept, Comparable<Thing> {
 
     FunctionalIterator<? extends Relation> getRelations(List<? extends RoleType> roleTypes);
 
+    /**
+     * Get all {@code Relation} instances of the given relation type, or its subtypes, that this {@code Thing}
+     * is playing the given role, or its subtypes, in. Only edges of the requested relation types are scanned,
+     * so this is cheaper than filtering {@link #getRelations(RoleType...)} for things playing in many relations.
+     *
+     * @param relationType The type of relations to retrieve
+     * @param roleType     The role type that this {@code Thing} plays in the relations
+     * @return an iterator of {@code Relation} of the given type that this {@code Thing} plays the given role in
+     */
+    FunctionalIterator<? extends Relation> getRelations(RelationType relationType, RoleType roleType);
+
     /**
      * Returns true if this {@code Thing} has been deleted.
      *
@@ -6,6 +6,8 @@
 
 package com.vaticle.typedb.core.concept.thing.impl;
 
+import com.vaticle.typedb.core.common.collection.ByteArray;
+import com.vaticle.typedb.core.common.collection.KeyValue;
 import com.vaticle.typedb.core.common.exception.TypeDBException;
 import com.vaticle.typedb.core.common.iterator.FunctionalIterator;
 import com.vaticle.typedb.core.common.iterator.sorted.SortedIterator.Forwardable;
@@ -95,6 +97,57 @@ public class RelationImpl extends ThingImpl implements Relation {
         super.delete();
     }
 
+    /**
+     * Checks that the optimised ROLEPLAYER edges of this relation agree with the role vertices that connect it to
+     * its players: every role vertex must have a ROLEPLAYER edge, and every ROLEPLAYER edge must have a role vertex.
+     */
+    public boolean isRolePlayerIndexConsistent() {
+        return missingRolePlayerEdges().isEmpty() && danglingRolePlayerEdges().isEmpty();
+    }
+
+    /**
+     * Re-creates ROLEPLAYER edges missing for existing role vertices, and deletes ROLEPLAYER edges
+     * whose role vertex no longer exists.
+     *
+     * @return true if any edge was repaired
+     */
+    public boolean repairRolePlayerIndex() {
+        List<ThingVertex> missing = missingRolePlayerEdges();
+        List<KeyValue<ThingVertex, ThingVertex>> dangling = danglingRolePlayerEdges();
+        missing.forEach(role -> RoleImpl.of(role).optimise());
+        dangling.forEach(playerAndRole -> writableVertex().outs().edge(
+                ROLEPLAYER, playerAndRole.key(), playerAndRole.value()
+        ).delete());
+        return !missing.isEmpty() || !dangling.isEmpty();
+    }
+
+    /**
+     * Returns the IIDs of role vertices of this relation that have no PLAYING edge. Their player is unknown, so
+     * the role player index cannot be repaired for them, and they are reported instead.
+     */
+    public List<ByteArray> getOrphanedRoles() {
+        return readableVertex().outs().edge(RELATING).to()
+                .filter(role -> !role.ins().edge(PLAYING).from().hasNext())
+                .map(role -> role.iid().bytes()).toList();
+    }
+
+    private List<ThingVertex> missingRolePlayerEdges() {
+        ThingVertex relation = readableVertex();
+        return relation.outs().edge(RELATING).to().filter(role -> {
+            Optional<ThingVertex> player = role.ins().edge(PLAYING).from().first();
+            return player.isPresent() && relation.outs().edge(ROLEPLAYER, player.get(), role) == null;
+        }).toList();
+    }
+
+    private List<KeyValue<ThingVertex, ThingVertex>> danglingRolePlayerEdges() {
+        ThingVertex relation = readableVertex();
+        return getType().getRelates().flatMap(
+                roleType -> relation.outs().edge(ROLEPLAYER, ((RoleTypeImpl) roleType).vertex).toAndOptimised()
+        ).filter(playerAndRole -> playerAndRole.value().ins().edge(RELATING, relation) == null ||
+                playerAndRole.value().ins().edge(PLAYING, playerAndRole.key()) == null
+        ).toList();
+    }
+
     public boolean deleteIfNoPlayer() {
         if (!writableVertex().outs().edge(RELATING).to().hasNext()) {
             this.delete();
@@ -15,15 +15,18 @@ import com.vaticle.typedb.core.concept.ConceptManager;
 import com.vaticle.typedb.core.concept.thing.Attribute;
 import com.vaticle.typedb.core.concept.thing.Thing;
 import com.vaticle.typedb.core.concept.type.AttributeType;
+import com.vaticle.typedb.core.concept.type.RelationType;
 import com.vaticle.typedb.core.concept.type.RoleType;
 import com.vaticle.typedb.core.concept.type.ThingType;
 import com.vaticle.typedb.core.concept.type.Type;
+import com.vaticle.typedb.core.concept.type.impl.RelationTypeImpl;
 import com.vaticle.typedb.core.concept.type.impl.RoleTypeImpl;
 import com.vaticle.typedb.core.concept.type.impl.TypeImpl;
 import com.vaticle.typedb.core.encoding.iid.PrefixIID;
 import com.vaticle.typedb.core.graph.edge.ThingEdge;
 import com.vaticle.typedb.core.graph.vertex.AttributeVertex;
 import com.vaticle.typedb.core.graph.vertex.ThingVertex;
+import com.vaticle.typedb.core.graph.vertex.TypeVertex;
 import com.vaticle.typeql.lang.common.TypeQLToken.Annotation;
 
 import java.util.Arrays;
@@ -257,6 +260,18 @@ public abstract class ThingImpl extends ConceptImpl implements Thing {
         }
     }
 
+    @Override
+    public FunctionalIterator<RelationImpl> getRelations(RelationType relationType, RoleType roleType) {
+        Set<? extends RoleType> roleTypes = roleType.getSubtypes().toSet();
+        return relationType.getSubtypes().flatMap(relType -> {
+            TypeVertex relationTypeVertex = ((RelationTypeImpl) relType).vertex;
+            return relType.getRelates().filter(roleTypes::contains).flatMap(rt -> readableVertex().ins().edge(
+                    ROLEPLAYER, ((RoleTypeImpl) rt).vertex,
+                    PrefixIID.of(relationTypeVertex.encoding().instance()), relationTypeVertex.iid()
+            ).from());
+        }).map(v -> RelationImpl.of(conceptMgr, v));
+    }
+
     @Override
     public void delete() {
         writableVertex().outs().edge(PLAYING).to().map(RoleImpl::of).forEachRemaining(RoleImpl::delete);
@@ -60,6 +60,8 @@ import java.util.concurrent.atomic.AtomicBoolean;
 import java.util.concurrent.atomic.AtomicInteger;
 import java.util.concurrent.atomic.AtomicLong;
 import java.util.concurrent.atomic.AtomicReference;
+import java.util.concurrent.locks.ReadWriteLock;
+import java.util.concurrent.locks.ReentrantReadWriteLock;
 import java.util.concurrent.locks.StampedLock;
 import java.util.stream.Stream;
 
@@ -106,6 +108,7 @@ public class CoreDatabase implements TypeDB.Database {
     private final AtomicLong nextTransactionID;
     private final AtomicInteger schemaLockWriteRequests;
     private final StampedLock schemaLock;
+    private final ReadWriteLock compactionLock;
     protected final ConcurrentMap<UUID, Pair<CoreSession, Long>> sessions;
     protected final RocksConfiguration rocksConfiguration;
     protected final KeyGenerator.Schema.Persisted schemaKeyGenerator;
@@ -131,9 +134,10 @@ public class CoreDatabase implements TypeDB.Database {
         isolationMgr = new IsolationManager();
         statisticsCorrector = createStatisticsCorrector();
         sessions = new ConcurrentHashMap<>();
-        rocksConfiguration = new RocksConfiguration(options().storageDataCacheSize(),
-                options().storageIndexCacheSize(), LOG.isDebugEnabled() || LOG.isTraceEnabled(), ROCKS_LOG_PERIOD);
+        rocksConfiguration = new RocksConfiguration(options().storageDataCacheSize(), options().storageIndexCacheSize(),
+                options().storageCompactionRateLimit(), LOG.isDebugEnabled() || LOG.isTraceEnabled(), ROCKS_LOG_PERIOD);
         schemaLock = new StampedLock();
+        compactionLock = new ReentrantReadWriteLock();
         schemaLockWriteRequests = new AtomicInteger(0);
         nextTransactionID = new AtomicLong(0);
         isOpen = new AtomicBoolean(false);
@@ -525,6 +529,31 @@ public class CoreDatabase implements TypeDB.Database {
         }
     }
 
+    /**
+     * Manually compact every data partition, removing tombstones left behind by large deletes. RocksDB only
+     * compacts in the background once a level's size threshold is reached, so prefix scans over ranges that
+     * were bulk-deleted can remain slow until then.
+     *
+     * Closing the database waits for a running compaction to finish, as RocksDB must not be closed under it.
+     */
+    public void compact() {
+        if (!isOpen.get()) throw TypeDBException.of(DATABASE_CLOSED, name);
+        for (Key.Partition partition : rocksDataPartitionMgr.partitions()) compact(partition);
+    }
+
+    public void compact(Key.Partition partition) {
+        compactionLock.readLock().lock();
+        try {
+            if (!isOpen.get()) throw TypeDBException.of(DATABASE_CLOSED, name);
+            LOG.debug("Compacting partition '{}' of database '{}'", partition, name);
+            rocksData.compactRange(rocksDataPartitionMgr.get(partition));
+        } catch (RocksDBException e) {
+            throw TypeDBException.of(STORAGE_ERROR, e);
+        } finally {
+            compactionLock.readLock().unlock();
+        }
+    }
+
     @Override
     public String schema() {
         try (TypeDB.Session session = databaseMgr.session(name, DATA); TypeDB.Transaction tx = session.transaction(READ)) {
@@ -562,7 +591,12 @@ public class CoreDatabase implements TypeDB.Database {
     public void close() {
         if (isOpen.compareAndSet(true, false)) {
             if (scheduledPropertiesLogger != null) shutdownRocksPropertiesLogger();
-            closeResources();
+            compactionLock.writeLock().lock();
+            try {
+                closeResources();
+            } finally {
+                compactionLock.writeLock().unlock();
+            }
         }
     }
 
@@ -13,6 +13,7 @@ import org.rocksdb.ColumnFamilyOptions;
 import org.rocksdb.DBOptions;
 import org.rocksdb.IndexType;
 import org.rocksdb.LRUCache;
+import org.rocksdb.RateLimiter;
 import org.rocksdb.Statistics;
 import org.rocksdb.UInt64AddOperator;
 
@@ -28,9 +29,10 @@ public class RocksConfiguration {
     private final Data dataOptions;
     private final boolean loggingEnabled;
 
-    public RocksConfiguration(long dataCacheSize, long indexCacheSize, boolean loggingEnabled, int logStatisticsPeriodSec) {
+    public RocksConfiguration(long dataCacheSize, long indexCacheSize, long compactionRateLimit,
+                              boolean loggingEnabled, int logStatisticsPeriodSec) {
         this.schemaOptions = new Schema();
-        this.dataOptions = new Data(dataCacheSize, indexCacheSize, loggingEnabled, logStatisticsPeriodSec);
+        this.dataOptions = new Data(dataCacheSize, indexCacheSize, compactionRateLimit, loggingEnabled, logStatisticsPeriodSec);
         this.loggingEnabled = loggingEnabled;
     }
 
@@ -77,11 +79,13 @@ public class RocksConfiguration {
     static class Data {
 
         private final LRUCache blockCache;
+        private final long compactionRateLimit;
         private final boolean logStatistics;
         private final int logStatisticsPeriodSec;
 
-        Data(long dataCacheSize, long indexCacheSize, boolean logStatistics, int logStatisticsPeriodSec) {
+        Data(long dataCacheSize, long indexCacheSize, long compactionRateLimit, boolean logStatistics, int logStatisticsPeriodSec) {
             this.blockCache = lruCache(dataCacheSize, indexCacheSize);
+            this.compactionRateLimit = compactionRateLimit;
             this.logStatistics = logStatistics;
             this.logStatisticsPeriodSec = logStatisticsPeriodSec;
         }
@@ -115,6 +119,7 @@ public class RocksConfiguration {
         org.rocksdb.DBOptions dbOptions() {
             DBOptions dbOptions = new DBOptions().setCreateIfMissing(true);
             configureWriteConcurrency(dbOptions);
+            if (compactionRateLimit > 0) configureRateLimiter(dbOptions);
             if (logStatistics) configureStatistics(dbOptions);
             return dbOptions;
         }
@@ -135,6 +140,15 @@ public class RocksConfiguration {
                     .setAllowConcurrentMemtableWrite(true);
         }
 
+        /**
+         * Background flushes and compactions can saturate the disk after large writes or deletes, starving foreground reads.
+         * A RocksDB `RateLimiter` caps the bytes per second these background jobs may write; foreground writes to the WAL
+         * and memtables are not throttled.
+         */
+        private void configureRateLimiter(DBOptions options) {
+            options.setRateLimiter(new RateLimiter(compactionRateLimit));
+        }
+
         /**
          * We can make RocksDB print statistics for block cache, filtering, get/write timing statistics, we have to set two options:
          * `setStatistics(new Statistics())` is required, and one can read the the Java statistics option to get the values back.
@@ -312,6 +312,10 @@ public class RocksProperties {
                         format("%-40s %s \n", "Raw values size", formatBytesToMB(rawValuesSize)) +
                         format("%-40s %d (%s) \n", "Approximate total keys", stream(keysEstimate).sum(),
                                 stream(keysEstimate).mapToObj(l -> "" + l).collect(Collectors.joining("/"))) +
+                        format("%-40s %d (%s) \n", "Deletion tombstones", stream(numDeletions).sum(),
+                                stream(numDeletions).mapToObj(l -> "" + l).collect(Collectors.joining("/"))) +
+                        format("%-40s %s %%\n", "Tombstone ratio (deletions/entries)",
+                                formatSeparated1f(numDeletions, (v, i) -> numEntries[i] == 0 ? 0f : 100f * v / numEntries[i])) +
                         format("%-40s %s bytes\n", "Bytes per key (raw values/approx keys)",
                                 formatSeparated1f(rawKeysSize, (v, i) -> (float) v / (keysEstimate[i]))) +
                         format("%-40s %s bytes\n", "Bytes per value (raw values/approx keys)",
@@ -70,7 +70,8 @@ public abstract class RocksStorage implements Storage {
     private final WriteOptions writeOptions;
     private final AtomicBoolean isOpen;
 
-    private RocksStorage(OptimisticTransactionDB rocksDB, CorePartitionManager partitionMgr, boolean isReadOnly) {
+    private RocksStorage(OptimisticTransactionDB rocksDB, CorePartitionManager partitionMgr, boolean isReadOnly,
+                         long readaheadSize) {
         this.isReadOnly = isReadOnly;
         this.partitionMgr = partitionMgr;
         iterators = new ConcurrentSet<>();
@@ -83,6 +84,7 @@ public abstract class RocksStorage implements Storage {
         rocksTransaction = rocksDB.beginTransaction(writeOptions, transactionOptions);
         snapshot = rocksTransaction.getSnapshot();
         readOptions = new ReadOptions().setSnapshot(snapshot).setTotalOrderSeek(true);
+        if (readaheadSize > 0) readOptions.setReadaheadSize(readaheadSize);
         readOptionsWithPrefixBloom = new ReadOptions().setSnapshot(snapshot).setTotalOrderSeek(false);
         deleteCloseSchemaWriteLock = new StampedLock().asReadWriteLock();
         isOpen = new AtomicBoolean(true);
@@ -205,7 +207,7 @@ public abstract class RocksStorage implements Storage {
     static class Cache extends RocksStorage {
 
         Cache(OptimisticTransactionDB rocksDB, CorePartitionManager partitionMgr) {
-            super(rocksDB, partitionMgr, true);
+            super(rocksDB, partitionMgr, true, 0);
         }
 
         @Override
@@ -240,7 +242,7 @@ public abstract class RocksStorage implements Storage {
         protected final CoreTransaction transaction;
 
         TransactionBounded(OptimisticTransactionDB rocksDB, CorePartitionManager partitionMgr, CoreTransaction transaction) {
-            super(rocksDB, partitionMgr, transaction.type().isRead());
+            super(rocksDB, partitionMgr, transaction.type().isRead(), transaction.context().options().storageReadaheadSize());
             this.transaction = transaction;
         }
 
@@ -21,6 +21,7 @@ import java.util.concurrent.atomic.AtomicBoolean;
 import java.util.concurrent.locks.ReadWriteLock;
 import java.util.concurrent.locks.StampedLock;
 
+import static com.vaticle.typedb.core.common.exception.ErrorMessage.Server.SESSION_TRANSACTION_LIMIT_REACHED;
 import static com.vaticle.typedb.core.common.exception.ErrorMessage.Session.SESSION_CLOSED;
 import static com.vaticle.typedb.core.concurrent.executor.Executors.scheduled;
 import static java.util.concurrent.TimeUnit.MILLISECONDS;
@@ -30,6 +31,7 @@ public class SessionService implements AutoCloseable {
     private static final Logger LOG = LoggerFactory.getLogger(SessionService.class);
 
     private final ConcurrentSet<TransactionService> transactionServices;
+    private final TransactionLimit transactionLimit;
     private final TypeDBService typeDBSvc;
     private final Options.Session options;
     private final TypeDB.Session session;
@@ -39,12 +41,18 @@ public class SessionService implements AutoCloseable {
     private ScheduledFuture<?> idleTimeoutTask;
 
     public SessionService(TypeDBService typeDBSvc, TypeDB.Session session, Options.Session options) {
+        this(typeDBSvc, session, options, 0);
+    }
+
+    public SessionService(TypeDBService typeDBSvc, TypeDB.Session session, Options.Session options,
+                          int transactionLimit) {
         this.typeDBSvc = typeDBSvc;
         this.session = session;
         this.options = options;
         this.accessLock = new StampedLock().asReadWriteLock();
         this.isOpen = new AtomicBoolean(true);
         this.transactionServices = new ConcurrentSet<>();
+        this.transactionLimit = new TransactionLimit(transactionLimit, SESSION_TRANSACTION_LIMIT_REACHED);
         startIdleTimeout();
         this.openTime = Instant.now();
     }
@@ -53,6 +61,7 @@ public class SessionService implements AutoCloseable {
         try {
             accessLock.readLock().lock();
             if (isOpen.get()) {
+                transactionLimit.acquire();
                 transactionServices.add(transactionSvc);
                 cancelIdleTimeout();
             } else throw TypeDBException.of(SESSION_CLOSED);
@@ -62,7 +71,7 @@ public class SessionService implements AutoCloseable {
     }
 
     void closed(TransactionService transactionSvc) {
-        transactionServices.remove(transactionSvc);
+        if (transactionServices.remove(transactionSvc)) transactionLimit.release();
         mayStartIdleTimeout();
     }
 
@@ -0,0 +1,43 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+package com.vaticle.typedb.core.server;
+
+import com.vaticle.typedb.core.common.exception.ErrorMessage;
+import com.vaticle.typedb.core.common.exception.TypeDBException;
+
+import java.util.concurrent.atomic.AtomicInteger;
+
+/**
+ * Counts open transactions against a limit, where a limit of 0 means no limit.
+ */
+class TransactionLimit {
+
+    private final int limit;
+    private final ErrorMessage limitReached;
+    private final AtomicInteger open;
+
+    TransactionLimit(int limit, ErrorMessage limitReached) {
+        this.limit = limit;
+        this.limitReached = limitReached;
+        this.open = new AtomicInteger(0);
+    }
+
+    void acquire() {
+        if (open.incrementAndGet() > limit && limit > 0) {
+            open.decrementAndGet();
+            throw TypeDBException.of(limitReached, limit);
+        }
+    }
+
+    void release() {
+        open.decrementAndGet();
+    }
+
+    int open() {
+        return open.get();
+    }
+}
@@ -6,6 +6,8 @@
 
 package com.vaticle.typedb.core.server;
 
+import com.google.protobuf.MessageOrBuilder;
+import com.google.protobuf.TextFormat;
 import com.vaticle.typedb.core.TypeDB;
 import com.vaticle.typedb.core.common.diagnostics.Diagnostics;
 import com.vaticle.typedb.core.common.diagnostics.Metrics;
@@ -19,6 +21,7 @@ import com.vaticle.typedb.core.server.common.SynchronizedStreamObserver;
 import com.vaticle.typedb.core.server.concept.ConceptService;
 import com.vaticle.typedb.core.server.concept.ThingService;
 import com.vaticle.typedb.core.server.concept.TypeService;
+import com.vaticle.typedb.core.server.logging.AuditLog;
 import com.vaticle.typedb.core.server.logic.LogicService;
 import com.vaticle.typedb.core.server.logic.RuleService;
 import com.vaticle.typedb.core.server.query.QueryService;
@@ -63,6 +66,7 @@ import static com.vaticle.typedb.core.concurrent.executor.Executors.scheduled;
 import static com.vaticle.typedb.core.server.common.RequestReader.applyDefaultOptions;
 import static com.vaticle.typedb.core.server.common.RequestReader.byteStringAsUUID;
 import static com.vaticle.typedb.core.server.common.ResponseBuilder.Transaction.serverMsg;
+import static com.vaticle.typedb.core.server.common.ResponseBuilder.isResourceExhausted;
 import static com.vaticle.typedb.protocol.TransactionProto.Transaction.Stream.State.CONTINUE;
 import static com.vaticle.typedb.protocol.TransactionProto.Transaction.Stream.State.DONE;
 import static java.util.concurrent.TimeUnit.MILLISECONDS;
@@ -78,6 +82,8 @@ public class TransactionService implements StreamObserver<TransactionProto.Trans
     private final AtomicBoolean isRPCAlive;
     private final AtomicBoolean isTransactionOpen;
     private final ReadWriteLock requestLock;
+    private final List<String> schemaQueries;
+    private final List<String> schemaOperations;
 
     private volatile SessionService sessionSvc;
     private volatile TypeDB.Transaction transaction;
@@ -102,6 +108,8 @@ public class TransactionService implements StreamObserver<TransactionProto.Trans
         this.isRPCAlive = new AtomicBoolean(true);
         this.isTransactionOpen = new AtomicBoolean(false);
         this.requestLock = new StampedLock().asReadWriteLock();
+        this.schemaQueries = new ArrayList<>();
+        this.schemaOperations = new ArrayList<>();
     }
 
     public Context.Transaction context() {
@@ -188,17 +196,30 @@ public class TransactionService implements StreamObserver<TransactionProto.Trans
         TransactionProto.Transaction.Open.Req openReq = request.getOpenReq();
         networkLatencyMillis = Math.min(openReq.getNetworkLatencyMillis(), MAX_NETWORK_LATENCY_MILLIS);
         sessionSvc = sessionService(openReq);
-        sessionSvc.register(this);
-        options = new Options.Transaction().parent(sessionSvc.options());
-        applyDefaultOptions(options, openReq.getOptions());
-        transaction = transaction(sessionSvc, openReq, options);
+        typeDBSvc.transactionOpening();
+        try {
+            sessionSvc.register(this);
+        } catch (Throwable e) {
+            typeDBSvc.transactionClosed();
+            throw e;
+        }
+        try {
+            options = new Options.Transaction().parent(sessionSvc.options());
+            applyDefaultOptions(options, openReq.getOptions());
+            transaction = transaction(sessionSvc, openReq, options);
+        } catch (Throwable e) {
+            sessionSvc.closed(this);
+            typeDBSvc.transactionClosed();
+            throw e;
+        }
         services = new Services();
-        respond(ResponseBuilder.Transaction.open(byteStringAsUUID(request.getReqId())));
-        isTransactionOpen.set(true);
-        scheduledTimeout = scheduled().schedule(this::timeout, options.transactionTimeoutMillis(), MILLISECONDS);
         Diagnostics.get().incrementCurrentCount(
                 databaseName(),
                 Metrics.ConnectionPeakCounts.Kind.getKind(sessionSvc.session().type(), transaction.type()));
+        // the transaction must be marked open before responding, so that close() releases it if the response fails
+        isTransactionOpen.set(true);
+        scheduledTimeout = scheduled().schedule(this::timeout, options.transactionTimeoutMillis(), MILLISECONDS);
+        respond(ResponseBuilder.Transaction.open(byteStringAsUUID(request.getReqId())));
     }
 
     protected SessionService sessionService(TransactionProto.Transaction.Open.Req req) {
@@ -216,13 +237,25 @@ public class TransactionService implements StreamObserver<TransactionProto.Trans
     }
 
     protected void commit(UUID requestID) {
-        transaction.commit();
+        if (sessionSvc.session().type().isSchema() && transaction.type().isWrite()) {
+            try {
+                transaction.commit();
+                AuditLog.schemaCommitted(databaseName(), schemaQueries, schemaOperations, null);
+            } catch (RuntimeException e) {
+                AuditLog.schemaCommitted(databaseName(), schemaQueries, schemaOperations, e);
+                throw e;
+            }
+        } else {
+            transaction.commit();
+        }
         respond(ResponseBuilder.Transaction.commit(requestID));
         close();
     }
 
     protected void rollback(UUID requestID) {
         transaction.rollback();
+        schemaQueries.clear();
+        schemaOperations.clear();
         respond(ResponseBuilder.Transaction.rollback(requestID));
     }
 
@@ -238,6 +271,7 @@ public class TransactionService implements StreamObserver<TransactionProto.Trans
     protected void executeConceptRequest(TransactionProto.Transaction.Req req) {
         try {
             services.concept.execute(req);
+            maySchemaOperationExecuted(req, req.getConceptManagerReq());
         } catch (RuntimeException e) {
             if (e instanceof TypeDBException) throw e;
             else throw TypeDBException.of(ErrorMessage.Transaction.CONCEPT_ERROR, e);
@@ -247,6 +281,7 @@ public class TransactionService implements StreamObserver<TransactionProto.Trans
     protected void executeLogicRequest(TransactionProto.Transaction.Req req) {
         try {
             services.logic.execute(req);
+            maySchemaOperationExecuted(req, req.getLogicManagerReq());
         } catch (RuntimeException e) {
             if (e instanceof TypeDBException) throw e;
             else throw TypeDBException.of(ErrorMessage.Transaction.LOGIC_ERROR, e);
@@ -265,6 +300,7 @@ public class TransactionService implements StreamObserver<TransactionProto.Trans
     protected void executeTypeRequest(TransactionProto.Transaction.Req req) {
         try {
             services.type.execute(req);
+            maySchemaOperationExecuted(req, req.getTypeReq());
         } catch (RuntimeException e) {
             if (e instanceof TypeDBException) throw e;
             else throw TypeDBException.of(ErrorMessage.Transaction.CONCEPT_ERROR, e);
@@ -274,12 +310,23 @@ public class TransactionService implements StreamObserver<TransactionProto.Trans
     protected void executeRuleRequest(TransactionProto.Transaction.Req req) {
         try {
             services.rule.execute(req);
+            maySchemaOperationExecuted(req, req.getRuleReq());
         } catch (RuntimeException e) {
             if (e instanceof TypeDBException) throw e;
             else throw TypeDBException.of(ErrorMessage.Transaction.LOGIC_ERROR, e);
         }
     }
 
+    public void schemaQueryExecuted(String query) {
+        schemaQueries.add(query);
+    }
+
+    private void maySchemaOperationExecuted(TransactionProto.Transaction.Req req, MessageOrBuilder operation) {
+        if (sessionSvc.session().type().isSchema() && transaction.type().isWrite() && isWriteRequest(req)) {
+            schemaOperations.add(TextFormat.shortDebugString(operation));
+        }
+    }
+
     public void respond(TransactionProto.Transaction.Res response) {
         responder.onNext(serverMsg(response));
     }
@@ -468,8 +515,10 @@ public class TransactionService implements StreamObserver<TransactionProto.Trans
     public void close() {
         if (isRPCAlive.compareAndSet(true, false)) {
             if (isTransactionOpen.compareAndSet(true, false)) {
+                services.query.close();
                 transaction.close();
                 sessionSvc.closed(this);
+                typeDBSvc.transactionClosed();
                 Diagnostics.get().decrementCurrentCount(
                         databaseName(),
                         Metrics.ConnectionPeakCounts.Kind.getKind(sessionSvc.session().type(), transaction.type()));
@@ -482,8 +531,10 @@ public class TransactionService implements StreamObserver<TransactionProto.Trans
     public void close(Throwable error) {
         if (isRPCAlive.compareAndSet(true, false)) {
             if (isTransactionOpen.compareAndSet(true, false)) {
+                services.query.close();
                 transaction.close();
                 sessionSvc.closed(this);
+                typeDBSvc.transactionClosed();
                 Diagnostics.get().decrementCurrentCount(
                         databaseName(),
                         Metrics.ConnectionPeakCounts.Kind.getKind(sessionSvc.session().type(), transaction.type()));
@@ -492,7 +543,7 @@ public class TransactionService implements StreamObserver<TransactionProto.Trans
             responder.onError(ResponseBuilder.exception(error));
             // TODO: We should restrict the type of errors that we log.
             //       Expected error handling from the server side does not need to be logged - they create noise.
-            if (isClientCancelled(error)) LOG.debug(error.getMessage(), error);
+            if (isClientCancelled(error) || isResourceExhausted(error)) LOG.debug(error.getMessage(), error);
             else {
                 LOG.error(error.getMessage().trim());
                 Diagnostics.get().submitError(databaseName(), error);
@@ -29,13 +29,18 @@ import com.vaticle.typedb.protocol.TypeDBGrpc;
 import io.grpc.Metadata;
 import io.grpc.ServerCall;
 import io.grpc.ServerCallExecutorSupplier;
+import io.grpc.netty.GrpcSslContexts;
 import io.grpc.netty.NettyServerBuilder;
 import io.netty.channel.socket.nio.NioServerSocketChannel;
+import io.netty.handler.ssl.ClientAuth;
+import io.netty.handler.ssl.SslContext;
+import io.netty.handler.ssl.SslContextBuilder;
 import io.sentry.Sentry;
 import org.slf4j.Logger;
 import org.slf4j.LoggerFactory;
 
 import javax.annotation.Nullable;
+import javax.net.ssl.SSLException;
 import java.io.IOException;
 import java.net.BindException;
 import java.net.InetAddress;
@@ -60,6 +65,7 @@ import static com.vaticle.typedb.core.common.exception.ErrorMessage.Internal.UNK
 import static com.vaticle.typedb.core.common.exception.ErrorMessage.Server.DATA_DIRECTORY_NOT_WRITABLE;
 import static com.vaticle.typedb.core.common.exception.ErrorMessage.Server.FAILED_AT_STOPPING;
 import static com.vaticle.typedb.core.common.exception.ErrorMessage.Server.FAILED_TO_CREATE_DATA_DIRECTORY;
+import static com.vaticle.typedb.core.common.exception.ErrorMessage.Server.FAILED_TO_LOAD_TLS_CONFIGURATION;
 import static com.vaticle.typedb.core.common.exception.ErrorMessage.Server.INCOMPATIBLE_JAVA_RUNTIME;
 import static com.vaticle.typedb.core.common.exception.ErrorMessage.Server.PORT_IN_USE;
 import static com.vaticle.typedb.core.common.exception.ErrorMessage.Server.UNCAUGHT_ERROR;
@@ -115,8 +121,13 @@ public class TypeDBServer implements AutoCloseable {
                 .dataDir(config.storage().dataDir())
                 .storageDataCacheSize(config.storage().databaseCache().dataSize())
                 .storageIndexCacheSize(config.storage().databaseCache().indexSize())
+                .storageReadaheadSize(config.storage().readaheadSize())
+                .storageCompactionRateLimit(config.storage().compactionRateLimit())
                 .reasonerDebuggerDir(config.log().debugger().reasonerTracer().output().baseDirectory())
-                .reasonerPerfCounters(config.log().debugger().reasonerPerfCounters().enabled());
+                .reasonerPerfCounters(config.log().debugger().reasonerPerfCounters().enabled())
+                .slowQueryLog(config.log().slowQuery().enabled())
+                .slowQueryThresholdMillis(config.log().slowQuery().thresholdMillis())
+                .slowQuerySamplingRate(config.log().slowQuery().samplingRate());
 
         this.factory = factory;
         databaseMgr = factory.databaseManager(options);
@@ -250,11 +261,15 @@ public class TypeDBServer implements AutoCloseable {
     protected io.grpc.Server rpcServer() {
         assert Executors.isInitialised();
 
-        typeDBService = new TypeDBService(config.server().address(), databaseMgr);
+        typeDBService = new TypeDBService(
+                config.server().address(), databaseMgr, config.server().maxConcurrentTransactions(),
+                config.server().maxConcurrentTransactionsPerSession()
+        );
         MigratorService migratorService = new MigratorService(databaseMgr, Version.VERSION);
 
-        return NettyServerBuilder.forAddress(config.server().address())
-                .executor(Executors.network()) //  "executor(Executor) runs necessary tasks before the ServerCallExecutorSupplier is ready to be called, then it switches over."
+        NettyServerBuilder builder = NettyServerBuilder.forAddress(config.server().address());
+        if (config.server().encryption().enabled()) builder.sslContext(sslContext(config.server().encryption()));
+        return builder.executor(Executors.network()) //  "executor(Executor) runs necessary tasks before the ServerCallExecutorSupplier is ready to be called, then it switches over."
                 .callExecutor(new ExecutorSelector(Executors.service(), Executors.transactionService()))
                 .workerEventLoopGroup(Executors.network())
                 .bossEventLoopGroup(Executors.network())
@@ -265,6 +280,20 @@ public class TypeDBServer implements AutoCloseable {
                 .build();
     }
 
+    private static SslContext sslContext(CoreConfig.Server.Encryption encryption) {
+        try {
+            SslContextBuilder builder = GrpcSslContexts.forServer(
+                    encryption.certificate().toFile(), encryption.privateKey().toFile()
+            );
+            if (encryption.rootCA().isPresent()) {
+                builder.trustManager(encryption.rootCA().get().toFile()).clientAuth(ClientAuth.REQUIRE);
+            }
+            return builder.build();
+        } catch (SSLException | IllegalArgumentException e) {
+            throw TypeDBException.of(FAILED_TO_LOAD_TLS_CONFIGURATION, e.getMessage());
+        }
+    }
+
     protected String name() {
         return TYPEDB_DISTRIBUTION_NAME;
     }
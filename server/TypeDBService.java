@@ -14,6 +14,7 @@ import com.vaticle.typedb.core.common.parameters.Arguments;
 import com.vaticle.typedb.core.common.parameters.Options;
 import com.vaticle.typedb.core.concurrent.executor.Executors;
 import com.vaticle.typedb.core.server.common.ResponseBuilder;
+import com.vaticle.typedb.core.server.logging.AuditLog;
 import com.vaticle.typedb.protocol.ConnectionProto;
 import com.vaticle.typedb.protocol.DatabaseProto.Database;
 import com.vaticle.typedb.protocol.DatabaseProto.DatabaseManager;
@@ -68,6 +69,7 @@ import static com.vaticle.typedb.core.common.exception.ErrorMessage.Database.DAT
 import static com.vaticle.typedb.core.common.exception.ErrorMessage.Server.ERROR_LOGGING_CONNECTIONS;
 import static com.vaticle.typedb.core.common.exception.ErrorMessage.Server.PROTOCOL_VERSION_MISMATCH;
 import static com.vaticle.typedb.core.common.exception.ErrorMessage.Server.SERVER_SHUTDOWN;
+import static com.vaticle.typedb.core.common.exception.ErrorMessage.Server.TRANSACTION_LIMIT_REACHED;
 import static com.vaticle.typedb.core.common.exception.ErrorMessage.Server.USER_MANAGEMENT_NOT_AVAILABLE;
 import static com.vaticle.typedb.core.common.exception.ErrorMessage.Session.SESSION_NOT_FOUND;
 import static com.vaticle.typedb.core.server.common.RequestReader.applyDefaultOptions;
@@ -93,11 +95,26 @@ public class TypeDBService extends TypeDBGrpc.TypeDBImplBase {
     private final String address;
     protected final TypeDB.DatabaseManager databaseMgr;
     private final ConcurrentMap<UUID, SessionService> sessionServices;
+    private final TransactionLimit transactionLimit;
+    private final int sessionTransactionLimit;
 
     public TypeDBService(InetSocketAddress address, TypeDB.DatabaseManager databaseMgr) {
+        this(address, databaseMgr, 0, 0);
+    }
+
+    /**
+     * @param transactionLimit        maximum number of transactions open at once across all sessions,
+     *                                or 0 for no limit
+     * @param sessionTransactionLimit maximum number of transactions open at once in a single session,
+     *                                or 0 for no limit
+     */
+    public TypeDBService(InetSocketAddress address, TypeDB.DatabaseManager databaseMgr,
+                         int transactionLimit, int sessionTransactionLimit) {
         this.address = address.getHostString() + ":" + address.getPort();
         this.databaseMgr = databaseMgr;
         this.sessionServices = new ConcurrentHashMap<>();
+        this.transactionLimit = new TransactionLimit(transactionLimit, TRANSACTION_LIMIT_REACHED);
+        this.sessionTransactionLimit = sessionTransactionLimit;
 
         if (LOG.isDebugEnabled()) {
             Executors.scheduled().scheduleAtFixedRate(this::logConnectionStates, 0, 1, TimeUnit.MINUTES);
@@ -272,12 +289,16 @@ public class TypeDBService extends TypeDBGrpc.TypeDBImplBase {
 
     @Override
     public void databasesCreate(DatabaseManager.Create.Req request, StreamObserver<DatabaseManager.Create.Res> responder) {
+        boolean isAudited = false;
         try {
             doCreateDatabase(request.getName());
+            AuditLog.databaseCreated(request.getName(), null);
+            isAudited = true;
             responder.onNext(createRes());
             responder.onCompleted();
             Diagnostics.get().requestSuccess(request.getName(), DATABASES_CREATE);
         } catch (RuntimeException e) {
+            if (!isAudited) AuditLog.databaseCreated(request.getName(), e);
             LOG.error(e.getMessage(), e);
             Diagnostics.get().requestFail(request.getName(), DATABASES_CREATE);
             Diagnostics.get().submitError(request.getName(), e);
@@ -361,12 +382,16 @@ public class TypeDBService extends TypeDBGrpc.TypeDBImplBase {
 
     @Override
     public void databaseDelete(Database.Delete.Req request, StreamObserver<Database.Delete.Res> responder) {
+        boolean isAudited = false;
         try {
             doDeleteDatabase(request.getName());
+            AuditLog.databaseDeleted(request.getName(), null);
+            isAudited = true;
             responder.onNext(deleteRes());
             responder.onCompleted();
             Diagnostics.get().requestSuccess(request.getName(), DATABASE_DELETE);
         } catch (RuntimeException e) {
+            if (!isAudited) AuditLog.databaseDeleted(request.getName(), e);
             LOG.error(e.getMessage(), e);
             Diagnostics.get().requestFail(request.getName(), DATABASE_DELETE);
             Diagnostics.get().submitError(request.getName(), e);
@@ -464,7 +489,7 @@ public class TypeDBService extends TypeDBGrpc.TypeDBImplBase {
     }
 
     protected SessionService doCreateSessionService(TypeDB.Session session, Options.Session options) {
-        return new SessionService(this, session, options);
+        return new SessionService(this, session, options, sessionTransactionLimit);
     }
 
     public SessionService session(UUID uuid) {
@@ -475,6 +500,18 @@ public class TypeDBService extends TypeDBGrpc.TypeDBImplBase {
         sessionServices.remove(sessionSvc.UUID());
     }
 
+    void transactionOpening() {
+        transactionLimit.acquire();
+    }
+
+    void transactionClosed() {
+        transactionLimit.release();
+    }
+
+    int openTransactions() {
+        return transactionLimit.open();
+    }
+
     public void close() {
         sessionServices.values().parallelStream().forEach(s -> s.close(TypeDBException.of(SERVER_SHUTDOWN)));
         sessionServices.clear();
@@ -47,6 +47,8 @@ import java.util.regex.Pattern;
 import static com.google.protobuf.ByteString.copyFrom;
 import static com.vaticle.typedb.core.common.collection.ByteArray.encodeUUID;
 import static com.vaticle.typedb.core.common.exception.ErrorMessage.Internal.ILLEGAL_STATE;
+import static com.vaticle.typedb.core.common.exception.ErrorMessage.Server.SESSION_TRANSACTION_LIMIT_REACHED;
+import static com.vaticle.typedb.core.common.exception.ErrorMessage.Server.TRANSACTION_LIMIT_REACHED;
 import static com.vaticle.typedb.core.common.iterator.Iterators.iterate;
 import static com.vaticle.typedb.core.common.parameters.Concept.Existence.INFERRED;
 import static com.vaticle.typedb.core.server.common.ResponseBuilder.Answer.conceptMap;
@@ -67,9 +69,16 @@ public class ResponseBuilder {
     public static StatusRuntimeException exception(Throwable e) {
         if (e instanceof StatusRuntimeException) return (StatusRuntimeException) e;
         else if (e instanceof NullPointerException) return Status.INTERNAL.withDescription("Null Pointer Exception in TypeDB Server! This is a bug!").asRuntimeException();
+        else if (isResourceExhausted(e)) return Status.RESOURCE_EXHAUSTED.withDescription(e.getMessage()).asRuntimeException();
         else return Status.INTERNAL.withDescription(e.getMessage()).asRuntimeException();
     }
 
+    public static boolean isResourceExhausted(Throwable e) {
+        if (!(e instanceof TypeDBException)) return false;
+        ErrorMessage error = ((TypeDBException) e).errorMessage();
+        return error.equals(TRANSACTION_LIMIT_REACHED) || error.equals(SESSION_TRANSACTION_LIMIT_REACHED);
+    }
+
     public static ByteString UUIDAsByteString(UUID uuid) {
         return copyFrom(encodeUUID(uuid).getBytes());
     }
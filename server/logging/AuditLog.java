@@ -0,0 +1,63 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+package com.vaticle.typedb.core.server.logging;
+
+import com.eclipsesource.json.Json;
+import com.eclipsesource.json.JsonArray;
+import com.eclipsesource.json.JsonObject;
+import org.slf4j.Logger;
+import org.slf4j.LoggerFactory;
+
+import javax.annotation.Nullable;
+import java.util.List;
+
+/**
+ * Records administrative and schema operations as structured JSON lines on a dedicated logger,
+ * so they can be routed to their own output through the {@code log.logger} configuration.
+ * Each record carries the operation, the database and whether the operation succeeded.
+ * Schema commits list the TypeQL queries and the concept API schema requests executed in the transaction.
+ */
+public class AuditLog {
+
+    private static final Logger LOG = LoggerFactory.getLogger(AuditLog.class);
+
+    public static void databaseCreated(String database, @Nullable Throwable error) {
+        record("database-create", database, error);
+    }
+
+    public static void databaseDeleted(String database, @Nullable Throwable error) {
+        record("database-delete", database, error);
+    }
+
+    public static void schemaCommitted(String database, List<String> queries, List<String> conceptOperations,
+                                       @Nullable Throwable error) {
+        if (!LOG.isInfoEnabled()) return;
+        LOG.info(entry("schema-commit", database, error)
+                .add("queries", array(queries))
+                .add("concept-operations", array(conceptOperations))
+                .toString());
+    }
+
+    private static void record(String operation, String database, @Nullable Throwable error) {
+        if (LOG.isInfoEnabled()) LOG.info(entry(operation, database, error).toString());
+    }
+
+    private static JsonArray array(List<String> values) {
+        JsonArray array = Json.array();
+        values.forEach(array::add);
+        return array;
+    }
+
+    private static JsonObject entry(String operation, String database, @Nullable Throwable error) {
+        JsonObject entry = Json.object()
+                .add("operation", operation)
+                .add("database", database)
+                .add("success", error == null);
+        if (error != null) entry.add("error", error.getMessage());
+        return entry;
+    }
+}
@@ -7,6 +7,7 @@
 package com.vaticle.typedb.core.server.parameters;
 
 import com.vaticle.typedb.core.common.exception.TypeDBException;
+import com.vaticle.typedb.core.common.parameters.Options;
 import com.vaticle.typedb.core.server.parameters.util.YAMLParser;
 
 import javax.annotation.Nullable;
@@ -198,24 +199,101 @@ public class CoreConfig {
     public static class Server {
 
         private final InetSocketAddress address;
+        private final Encryption encryption;
+        private final int maxConcurrentTransactions;
+        private final int maxConcurrentTransactionsPerSession;
 
         protected Server(InetSocketAddress address) {
+            this(address, null, null, null);
+        }
+
+        protected Server(InetSocketAddress address, @Nullable Encryption encryption,
+                         @Nullable Integer maxConcurrentTransactions, @Nullable Integer maxConcurrentTransactionsPerSession) {
             this.address = address;
+            this.encryption = encryption != null ? encryption : Encryption.disabledEncryption();
+            this.maxConcurrentTransactions = maxConcurrentTransactions != null ? maxConcurrentTransactions : 0;
+            this.maxConcurrentTransactionsPerSession = maxConcurrentTransactionsPerSession != null ?
+                    maxConcurrentTransactionsPerSession : 0;
         }
 
         public InetSocketAddress address() {
             return address;
         }
+
+        public Encryption encryption() {
+            return encryption;
+        }
+
+        /**
+         * @return the maximum number of transactions that may be open at once, or 0 if unlimited
+         */
+        public int maxConcurrentTransactions() {
+            return maxConcurrentTransactions;
+        }
+
+        /**
+         * @return the maximum number of transactions that may be open at once in a single session, or 0 if unlimited
+         */
+        public int maxConcurrentTransactionsPerSession() {
+            return maxConcurrentTransactionsPerSession;
+        }
+
+        public static class Encryption {
+
+            private final boolean enable;
+            private final Path certificate;
+            private final Path privateKey;
+            private final Path rootCA;
+
+            Encryption(boolean enable, @Nullable Path certificate, @Nullable Path privateKey, @Nullable Path rootCA) {
+                this.enable = enable;
+                this.certificate = certificate;
+                this.privateKey = privateKey;
+                this.rootCA = rootCA;
+            }
+
+            static Encryption disabledEncryption() {
+                return new Encryption(false, null, null, null);
+            }
+
+            public boolean enabled() {
+                return enable;
+            }
+
+            public Path certificate() {
+                assert enable;
+                return certificate;
+            }
+
+            public Path privateKey() {
+                assert enable;
+                return privateKey;
+            }
+
+            public Optional<Path> rootCA() {
+                return Optional.ofNullable(rootCA);
+            }
+        }
     }
 
     public static class Storage {
 
         private final Path dataDir;
         private final DatabaseCache databaseCache;
+        private final long readaheadSize;
+        private final long compactionRateLimit;
 
         protected Storage(Path dataDir, DatabaseCache databaseCache) {
+            this(dataDir, databaseCache, null, null);
+        }
+
+        protected Storage(Path dataDir, DatabaseCache databaseCache, @Nullable Long readaheadSize,
+                          @Nullable Long compactionRateLimit) {
             this.dataDir = dataDir;
             this.databaseCache = databaseCache;
+            this.readaheadSize = readaheadSize != null ? readaheadSize : Options.DEFAULT_STORAGE_READAHEAD_SIZE;
+            this.compactionRateLimit = compactionRateLimit != null ?
+                    compactionRateLimit : Options.DEFAULT_STORAGE_COMPACTION_RATE_LIMIT;
         }
 
         public Path dataDir() {
@@ -226,6 +304,14 @@ public class CoreConfig {
             return databaseCache;
         }
 
+        public long readaheadSize() {
+            return readaheadSize;
+        }
+
+        public long compactionRateLimit() {
+            return compactionRateLimit;
+        }
+
         public static class DatabaseCache {
 
             private final long dataSize;
@@ -251,11 +337,17 @@ public class CoreConfig {
         private final Common.Output output;
         private final Logger logger;
         private final Debugger debugger;
+        private final SlowQuery slowQuery;
 
         public Log(Common.Output output, Logger logger, Debugger debugger) {
+            this(output, logger, debugger, null);
+        }
+
+        public Log(Common.Output output, Logger logger, Debugger debugger, @Nullable SlowQuery slowQuery) {
             this.output = output;
             this.logger = logger;
             this.debugger = debugger;
+            this.slowQuery = slowQuery != null ? slowQuery : SlowQuery.disabledSlowQuery();
         }
 
         public Common.Output output() {
@@ -270,6 +362,10 @@ public class CoreConfig {
             return debugger;
         }
 
+        public SlowQuery slowQuery() {
+            return slowQuery;
+        }
+
         public static class Logger {
 
             private final Unfiltered defaultLogger;
@@ -401,6 +497,35 @@ public class CoreConfig {
                 }
             }
         }
+
+        public static class SlowQuery {
+
+            private final boolean enable;
+            private final long thresholdMillis;
+            private final double samplingRate;
+
+            SlowQuery(boolean enable, long thresholdMillis, double samplingRate) {
+                this.enable = enable;
+                this.thresholdMillis = thresholdMillis;
+                this.samplingRate = samplingRate;
+            }
+
+            static SlowQuery disabledSlowQuery() {
+                return new SlowQuery(false, Options.DEFAULT_SLOW_QUERY_THRESHOLD_MILLIS, Options.DEFAULT_SLOW_QUERY_SAMPLING_RATE);
+            }
+
+            public boolean enabled() {
+                return enable;
+            }
+
+            public long thresholdMillis() {
+                return thresholdMillis;
+            }
+
+            public double samplingRate() {
+                return samplingRate;
+            }
+        }
     }
 
     public static class Diagnostics {
@@ -20,7 +20,9 @@ import static com.vaticle.typedb.common.collection.Collections.list;
 import static com.vaticle.typedb.common.collection.Collections.set;
 import static com.vaticle.typedb.core.common.exception.ErrorMessage.Internal.ILLEGAL_STATE;
 import static com.vaticle.typedb.core.common.exception.ErrorMessage.Server.CONFIGS_UNRECOGNISED;
+import static com.vaticle.typedb.core.common.exception.ErrorMessage.Server.CONFIG_KEY_MISSING;
 import static com.vaticle.typedb.core.common.exception.ErrorMessage.Server.CONFIG_SECTION_MUST_BE_MAP;
+import static com.vaticle.typedb.core.common.exception.ErrorMessage.Server.CONFIG_VALUE_UNEXPECTED;
 import static com.vaticle.typedb.core.common.exception.ErrorMessage.Server.CONFIG_YAML_MUST_BE_MAP;
 import static com.vaticle.typedb.core.common.iterator.Iterators.iterate;
 import static com.vaticle.typedb.core.server.common.Constants.TYPEDB_LOG_FILE_EXT;
@@ -32,6 +34,8 @@ import static com.vaticle.typedb.core.server.parameters.util.YAMLParser.KeyValue
 import static com.vaticle.typedb.core.server.parameters.util.YAMLParser.Value.Compound;
 import static com.vaticle.typedb.core.server.parameters.util.YAMLParser.Value.Primitive.BOOLEAN;
 import static com.vaticle.typedb.core.server.parameters.util.YAMLParser.Value.Primitive.BYTES_SIZE;
+import static com.vaticle.typedb.core.server.parameters.util.YAMLParser.Value.Primitive.DOUBLE;
+import static com.vaticle.typedb.core.server.parameters.util.YAMLParser.Value.Primitive.DURATION_MILLIS;
 import static com.vaticle.typedb.core.server.parameters.util.YAMLParser.Value.Primitive.INET_SOCKET_ADDRESS;
 import static com.vaticle.typedb.core.server.parameters.util.YAMLParser.Value.Primitive.INTEGER;
 import static com.vaticle.typedb.core.server.parameters.util.YAMLParser.Value.Primitive.LIST_STRING;
@@ -240,19 +244,84 @@ public class CoreConfigParser extends Compound<CoreConfig> {
 
         protected static final Predefined<InetSocketAddress> address =
                 predefined("address", "Address to listen for TypeDB Drivers on.", INET_SOCKET_ADDRESS);
-        private static final Set<Static> parsers = set(address);
+        protected static final KeyValue.Optional<CoreConfig.Server.Encryption> encryption =
+                optional(Encryption.name, Encryption.description, new Encryption());
+        protected static final KeyValue.Optional<Integer> maxConcurrentTransactions = optional(
+                "max-concurrent-transactions", "Maximum number of transactions open at once across all drivers. 0 for no limit.", INTEGER
+        );
+        protected static final KeyValue.Optional<Integer> maxConcurrentTransactionsPerSession = optional(
+                "max-concurrent-transactions-per-session", "Maximum number of transactions open at once in a single session. 0 for no limit.", INTEGER
+        );
+        private static final Set<Static> parsers = set(address, encryption, maxConcurrentTransactions,
+                maxConcurrentTransactionsPerSession);
 
         @Override
         public CoreConfig.Server parse(YAML yaml, String path) {
             if (yaml.isMap()) {
                 validateKeys(parsers, yaml.asMap().keys(), path);
-                return new CoreConfig.Server(address.parse(yaml.asMap(), path));
+                Integer transactionLimit = parseLimit(maxConcurrentTransactions, yaml, path);
+                Integer sessionTransactionLimit = parseLimit(maxConcurrentTransactionsPerSession, yaml, path);
+                return new CoreConfig.Server(address.parse(yaml.asMap(), path), encryption.parse(yaml.asMap(), path),
+                        transactionLimit, sessionTransactionLimit);
             } else throw TypeDBException.of(CONFIG_YAML_MUST_BE_MAP, path);
         }
 
+        private static Integer parseLimit(KeyValue.Optional<Integer> limit, YAML yaml, String path) {
+            Integer value = limit.parse(yaml.asMap(), path);
+            if (value != null && value < 0) {
+                throw TypeDBException.of(CONFIG_VALUE_UNEXPECTED, concatenate(path, limit.key()),
+                        value, "a non-negative integer");
+            }
+            return value;
+        }
+
         @Override
         public List<com.vaticle.typedb.core.server.parameters.util.Help> helpList(String path) {
-            return list(address.help(path));
+            return list(address.help(path), encryption.help(path), maxConcurrentTransactions.help(path),
+                    maxConcurrentTransactionsPerSession.help(path));
+        }
+
+        private static class Encryption extends Compound<CoreConfig.Server.Encryption> {
+
+            private static final String name = "encryption";
+            private static final String description = "TLS encryption of connections from TypeDB Drivers.";
+
+            private static final Predefined<Boolean> enable =
+                    predefined("enable", "Enable TLS encryption.", BOOLEAN);
+            private static final KeyValue.Optional<Path> certificate =
+                    optional("certificate", "Path to the server's PEM-encoded certificate chain.", PATH);
+            private static final KeyValue.Optional<Path> privateKey =
+                    optional("private-key", "Path to the server's PEM-encoded PKCS#8 private key.", PATH);
+            private static final KeyValue.Optional<Path> rootCA =
+                    optional("root-ca", "Path to a PEM-encoded CA certificate. If set, drivers must present a client certificate signed by this CA.", PATH);
+            private static final Set<Static> parsers = set(enable, certificate, privateKey, rootCA);
+
+            @Override
+            public CoreConfig.Server.Encryption parse(YAML yaml, String path) {
+                if (yaml.isMap()) {
+                    validateKeys(parsers, yaml.asMap().keys(), path);
+                    boolean enabled = enable.parse(yaml.asMap(), path);
+                    Path certificatePath = certificate.parse(yaml.asMap(), path);
+                    Path privateKeyPath = privateKey.parse(yaml.asMap(), path);
+                    Path rootCAPath = rootCA.parse(yaml.asMap(), path);
+                    if (enabled && certificatePath == null) {
+                        throw TypeDBException.of(CONFIG_KEY_MISSING, concatenate(path, certificate.key()));
+                    } else if (enabled && privateKeyPath == null) {
+                        throw TypeDBException.of(CONFIG_KEY_MISSING, concatenate(path, privateKey.key()));
+                    }
+                    return new CoreConfig.Server.Encryption(
+                            enabled,
+                            certificatePath == null ? null : configPathAbsolute(certificatePath),
+                            privateKeyPath == null ? null : configPathAbsolute(privateKeyPath),
+                            rootCAPath == null ? null : configPathAbsolute(rootCAPath)
+                    );
+                } else throw TypeDBException.of(CONFIG_SECTION_MUST_BE_MAP, path);
+            }
+
+            @Override
+            public List<com.vaticle.typedb.core.server.parameters.util.Help> helpList(String path) {
+                return list(enable.help(path), certificate.help(path), privateKey.help(path), rootCA.help(path));
+            }
         }
     }
 
@@ -265,20 +334,27 @@ public class CoreConfigParser extends Compound<CoreConfig> {
                 predefined("data", "Directory in which user databases will be stored.", PATH);
         protected static final Predefined<CoreConfig.Storage.DatabaseCache> dbCache =
                 predefined(DatabaseCache.name, DatabaseCache.description, new DatabaseCache());
-        private static final Set<Static> parsers = set(data, dbCache);
+        protected static final KeyValue.Optional<Long> readahead = optional(
+                "readahead", "Readahead size for storage iterators that scan across prefixes. 0 lets the storage engine tune readahead automatically.", BYTES_SIZE
+        );
+        protected static final KeyValue.Optional<Long> compactionRateLimit = optional(
+                "compaction-rate-limit", "Maximum disk write rate per second for background flushes and compactions. 0 means no limit.", BYTES_SIZE
+        );
+        private static final Set<Static> parsers = set(data, dbCache, readahead, compactionRateLimit);
 
         @Override
         public CoreConfig.Storage parse(YAML yaml, String path) {
             if (yaml.isMap()) {
                 validateKeys(parsers, yaml.asMap().keys(), path);
                 return new CoreConfig.Storage(configPathAbsolute(data.parse(yaml.asMap(), path)),
-                        dbCache.parse(yaml.asMap(), path));
+                        dbCache.parse(yaml.asMap(), path), readahead.parse(yaml.asMap(), path),
+                        compactionRateLimit.parse(yaml.asMap(), path));
             } else throw TypeDBException.of(CONFIG_YAML_MUST_BE_MAP, path);
         }
 
         @Override
         public List<com.vaticle.typedb.core.server.parameters.util.Help> helpList(String path) {
-            return list(data.help(path), dbCache.help(path));
+            return list(data.help(path), dbCache.help(path), readahead.help(path), compactionRateLimit.help(path));
         }
 
         private static class DatabaseCache extends Compound<CoreConfig.Storage.DatabaseCache> {
@@ -318,7 +394,9 @@ public class CoreConfigParser extends Compound<CoreConfig> {
                 predefined(Logger.name, Logger.description, new Logger());
         protected static final Predefined<CoreConfig.Log.Debugger> debugger =
                 predefined(Debugger.name, Debugger.description, new Debugger());
-        private static final Set<Static> parsers = set(output, logger, debugger);
+        protected static final KeyValue.Optional<CoreConfig.Log.SlowQuery> slowQuery =
+                optional(SlowQuery.name, SlowQuery.description, new SlowQuery());
+        private static final Set<Static> parsers = set(output, logger, debugger, slowQuery);
 
         @Override
         public CoreConfig.Log parse(YAML yaml, String path) {
@@ -329,13 +407,13 @@ public class CoreConfigParser extends Compound<CoreConfig> {
                 logger.validateOutputs(output.outputs());
                 CoreConfig.Log.Debugger debugger = Log.debugger.parse(yaml.asMap(), path);
                 debugger.validateAndSetOutputs(output.outputs());
-                return new CoreConfig.Log(output, logger, debugger);
+                return new CoreConfig.Log(output, logger, debugger, Log.slowQuery.parse(yaml.asMap(), path));
             } else throw TypeDBException.of(CONFIG_YAML_MUST_BE_MAP, path);
         }
 
         @Override
         public List<com.vaticle.typedb.core.server.parameters.util.Help> helpList(String path) {
-            return list(output.help(path), logger.help(path), debugger.help(path));
+            return list(output.help(path), logger.help(path), debugger.help(path), slowQuery.help(path));
         }
 
         private static class Logger extends Compound<CoreConfig.Log.Logger> {
@@ -491,6 +569,39 @@ public class CoreConfigParser extends Compound<CoreConfig> {
                 }
             }
         }
+
+        private static class SlowQuery extends Compound<CoreConfig.Log.SlowQuery> {
+
+            private static final String name = "slow-query";
+            private static final String description = "Log queries that take longer than a threshold to execute.";
+
+            private static final Predefined<Boolean> enable =
+                    predefined("enable", "Enable logging of slow queries.", BOOLEAN);
+            private static final Predefined<Long> threshold =
+                    predefined("threshold", "Minimum query execution time to be logged.", DURATION_MILLIS);
+            private static final Predefined<Double> samplingRate =
+                    predefined("sampling-rate", "Fraction of slow queries to log, between 0.0 and 1.0.", DOUBLE);
+            private static final Set<Static> parsers = set(enable, threshold, samplingRate);
+
+            @Override
+            public CoreConfig.Log.SlowQuery parse(YAML yaml, String path) {
+                if (yaml.isMap()) {
+                    validateKeys(parsers, yaml.asMap().keys(), path);
+                    long thresholdMillis = threshold.parse(yaml.asMap(), path);
+                    double rate = samplingRate.parse(yaml.asMap(), path);
+                    if (rate < 0.0 || rate > 1.0) {
+                        throw TypeDBException.of(CONFIG_VALUE_UNEXPECTED, concatenate(path, samplingRate.key()),
+                                rate, "a value between 0.0 and 1.0");
+                    }
+                    return new CoreConfig.Log.SlowQuery(enable.parse(yaml.asMap(), path), thresholdMillis, rate);
+                } else throw TypeDBException.of(CONFIG_SECTION_MUST_BE_MAP, path);
+            }
+
+            @Override
+            public List<com.vaticle.typedb.core.server.parameters.util.Help> helpList(String path) {
+                return list(enable.help(path), threshold.help(path), samplingRate.help(path));
+            }
+        }
     }
 
     protected static class Diagnostics extends Compound<CoreConfig.Diagnostics> {
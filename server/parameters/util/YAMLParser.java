@@ -331,8 +331,8 @@ public class YAMLParser {
                     "<int>"
             );
             public static final Primitive<Double> DOUBLE = new Primitive<>(
-                    (yaml) -> yaml.isDouble(),
-                    (yaml) -> yaml.asDouble().value(),
+                    (yaml) -> yaml.isDouble() || yaml.isInt(),
+                    (yaml) -> yaml.isInt() ? yaml.asInt().value() : yaml.asDouble().value(),
                     "<double>"
             );
             public static final Primitive<Boolean> BOOLEAN = new Primitive<>(
@@ -352,7 +352,12 @@ public class YAMLParser {
             );
             public static final Primitive<Long> DURATION = new Primitive<>(
                     (yaml) -> yaml.isString() && Duration.isValidString(yaml.asString().value()),
-                    (yaml) -> Duration.parse(yaml.asString().value()),
+                    (yaml) -> Duration.parse(yaml.asString().value()).toSeconds(),
+                    "<" + Duration.HELP + ">"
+            );
+            public static final Primitive<Long> DURATION_MILLIS = new Primitive<>(
+                    (yaml) -> yaml.isString() && Duration.isValidString(yaml.asString().value()),
+                    (yaml) -> Duration.parse(yaml.asString().value()).toMillis(),
                     "<" + Duration.HELP + ">"
             );
             public static final Primitive<InetSocketAddress> INET_SOCKET_ADDRESS = new Primitive<>(
@@ -490,15 +495,15 @@ public class YAMLParser {
 
         private static class Duration {
 
-            private final static String HELP = "<number> d|h|m|s";
+            private final static String HELP = "<number> d|h|m|s|ms";
 
             private final static String LENGTH_PART = "([0-9]+)";
             private final static int LENGTH_GROUP = 1;
-            private final static String UNIT_PART = "([dhms])";
+            private final static String UNIT_PART = "(ms|[dhms])";
             private final static int UNIT_GROUP = 2;
             private static final Pattern DURATION_PATTERN = Pattern.compile("^" + LENGTH_PART + "\\s*" + UNIT_PART + "$", Pattern.CASE_INSENSITIVE);
 
-            private static long parse(String durationString) {
+            private static java.time.Duration parse(String durationString) {
                 Matcher matcher = DURATION_PATTERN.matcher(durationString);
                 java.time.Duration duration;
                 if (matcher.matches()) {
@@ -513,8 +518,10 @@ public class YAMLParser {
                         duration = java.time.Duration.of(lenValue, ChronoUnit.MINUTES);
                     } else if (unitStr.equalsIgnoreCase("s")) {
                         duration = java.time.Duration.of(lenValue, ChronoUnit.SECONDS);
+                    } else if (unitStr.equalsIgnoreCase("ms")) {
+                        duration = java.time.Duration.of(lenValue, ChronoUnit.MILLIS);
                     } else throw new IllegalStateException("Unexpected duration unit: " + unitStr);
-                    return duration.toSeconds();
+                    return duration;
                 } else {
                     throw new IllegalArgumentException("Duration [" + durationString + "] is not in a recognised format.");
                 }
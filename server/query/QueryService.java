@@ -14,6 +14,7 @@ import com.vaticle.typedb.core.concept.answer.ConceptMap;
 import com.vaticle.typedb.core.concept.answer.ConceptMapGroup;
 import com.vaticle.typedb.core.concept.answer.ReadableConceptTree;
 import com.vaticle.typedb.core.concept.answer.ValueGroup;
+import com.vaticle.typedb.core.concept.value.Value;
 import com.vaticle.typedb.core.query.QueryManager;
 import com.vaticle.typedb.core.reasoner.answer.Explanation;
 import com.vaticle.typedb.core.server.TransactionService;
@@ -28,7 +29,10 @@ import com.vaticle.typeql.lang.query.TypeQLInsert;
 import com.vaticle.typeql.lang.query.TypeQLUndefine;
 import com.vaticle.typeql.lang.query.TypeQLUpdate;
 
+import java.util.Optional;
+import java.util.Set;
 import java.util.UUID;
+import java.util.concurrent.ConcurrentHashMap;
 
 import static com.vaticle.typedb.core.common.exception.ErrorMessage.Server.UNKNOWN_REQUEST_TYPE;
 import static com.vaticle.typedb.core.server.common.RequestReader.applyDefaultOptions;
@@ -45,15 +49,19 @@ import static com.vaticle.typedb.core.server.common.ResponseBuilder.QueryManager
 import static com.vaticle.typedb.core.server.common.ResponseBuilder.QueryManager.insertResPart;
 import static com.vaticle.typedb.core.server.common.ResponseBuilder.QueryManager.undefineRes;
 import static com.vaticle.typedb.core.server.common.ResponseBuilder.QueryManager.updateResPart;
+import static com.vaticle.typedb.core.server.query.SlowQueryLog.Outcome.COMPLETED;
+import static com.vaticle.typedb.core.server.query.SlowQueryLog.Outcome.FAILED;
 
 public class QueryService {
 
     private final QueryManager queryMgr;
     private final TransactionService transactionSvc;
+    private final Set<SlowQueryLog> streamingSlowQueryLogs;
 
     public QueryService(TransactionService transactionSvc, QueryManager queryMgr) {
         this.queryMgr = queryMgr;
         this.transactionSvc = transactionSvc;
+        this.streamingSlowQueryLogs = ConcurrentHashMap.newKeySet();
     }
 
     public void execute(TransactionProto.Transaction.Req req) {
@@ -61,6 +69,7 @@ public class QueryService {
         Options.Query options = new Options.Query();
         applyDefaultOptions(options, queryReq.getOptions());
         applyQueryOptions(options, queryReq.getOptions());
+        options.parent(transactionSvc.context().options());
         UUID reqID = byteStringAsUUID(req.getReqId());
         switch (queryReq.getReqCase()) {
             case DEFINE_REQ:
@@ -103,72 +112,167 @@ public class QueryService {
     }
 
     private void define(String queryStr, Options.Query options, UUID reqID) {
-        TypeQLDefine query = TypeQL.parseQuery(queryStr).asDefine();
-        Context.Query context = new Context.Query(transactionSvc.context(), options.query(query), query);
-        queryMgr.define(query, context);
+        SlowQueryLog slowQueryLog = slowQueryLog(queryStr, "define", options);
+        try {
+            TypeQLDefine query = TypeQL.parseQuery(queryStr).asDefine();
+            slowQueryLog.parsed();
+            Context.Query context = new Context.Query(transactionSvc.context(), options.query(query), query);
+            queryMgr.define(query, context);
+            slowQueryLog.finish(COMPLETED);
+        } finally {
+            slowQueryLog.finish(FAILED);
+        }
+        transactionSvc.schemaQueryExecuted(queryStr);
         transactionSvc.respond(defineRes(reqID));
     }
 
     private void undefine(String queryStr, Options.Query options, UUID reqID) {
-        TypeQLUndefine query = TypeQL.parseQuery(queryStr).asUndefine();
-        Context.Query context = new Context.Query(transactionSvc.context(), options.query(query), query);
-        queryMgr.undefine(query, context);
+        SlowQueryLog slowQueryLog = slowQueryLog(queryStr, "undefine", options);
+        try {
+            TypeQLUndefine query = TypeQL.parseQuery(queryStr).asUndefine();
+            slowQueryLog.parsed();
+            Context.Query context = new Context.Query(transactionSvc.context(), options.query(query), query);
+            queryMgr.undefine(query, context);
+            slowQueryLog.finish(COMPLETED);
+        } finally {
+            slowQueryLog.finish(FAILED);
+        }
+        transactionSvc.schemaQueryExecuted(queryStr);
         transactionSvc.respond(undefineRes(reqID));
     }
 
     private void get(String queryStr, Options.Query options, UUID reqID) {
-        TypeQLGet query = TypeQL.parseQuery(queryStr).asGet();
-        Context.Query context = new Context.Query(transactionSvc.context(), options.query(query), query);
-        FunctionalIterator<? extends ConceptMap> answers = queryMgr.get(query, context);
-        transactionSvc.stream(answers, reqID, context.options(), a -> getResPart(reqID, a));
+        SlowQueryLog slowQueryLog = slowQueryLog(queryStr, "get", options);
+        try {
+            TypeQLGet query = TypeQL.parseQuery(queryStr).asGet();
+            slowQueryLog.parsed();
+            Context.Query context = new Context.Query(transactionSvc.context(), options.query(query), query);
+            FunctionalIterator<? extends ConceptMap> answers = queryMgr.get(query, context);
+            slowQueryLog.prepared();
+            transactionSvc.stream(recordStream(slowQueryLog, answers), reqID, context.options(), a -> getResPart(reqID, a));
+        } catch (RuntimeException e) {
+            slowQueryLog.finish(FAILED);
+            throw e;
+        }
     }
 
     private void getAggregate(String queryStr, Options.Query options, UUID reqID) {
-        TypeQLGet.Aggregate query = TypeQL.parseQuery(queryStr).asGetAggregate();
-        Context.Query context = new Context.Query(transactionSvc.context(), options.query(query), query);
-        transactionSvc.respond(getAggregateRes(reqID, queryMgr.get(query, context)));
+        SlowQueryLog slowQueryLog = slowQueryLog(queryStr, "get-aggregate", options);
+        Optional<Value<?>> answer;
+        try {
+            TypeQLGet.Aggregate query = TypeQL.parseQuery(queryStr).asGetAggregate();
+            slowQueryLog.parsed();
+            Context.Query context = new Context.Query(transactionSvc.context(), options.query(query), query);
+            answer = queryMgr.get(query, context);
+            slowQueryLog.finish(COMPLETED);
+        } finally {
+            slowQueryLog.finish(FAILED);
+        }
+        transactionSvc.respond(getAggregateRes(reqID, answer));
     }
 
     private void getGroup(String queryStr, Options.Query options, UUID reqID) {
-        TypeQLGet.Group query = TypeQL.parseQuery(queryStr).asGetGroup();
-        Context.Query context = new Context.Query(transactionSvc.context(), options.query(query), query);
-        FunctionalIterator<ConceptMapGroup> answers = queryMgr.get(query, context);
-        transactionSvc.stream(answers, reqID, context.options(), a -> getGroupResPart(reqID, a));
+        SlowQueryLog slowQueryLog = slowQueryLog(queryStr, "get-group", options);
+        try {
+            TypeQLGet.Group query = TypeQL.parseQuery(queryStr).asGetGroup();
+            slowQueryLog.parsed();
+            Context.Query context = new Context.Query(transactionSvc.context(), options.query(query), query);
+            FunctionalIterator<ConceptMapGroup> answers = queryMgr.get(query, context);
+            slowQueryLog.prepared();
+            transactionSvc.stream(recordStream(slowQueryLog, answers), reqID, context.options(), a -> getGroupResPart(reqID, a));
+        } catch (RuntimeException e) {
+            slowQueryLog.finish(FAILED);
+            throw e;
+        }
     }
 
     private void getGroupAggregate(String queryStr, Options.Query options, UUID reqID) {
-        TypeQLGet.Group.Aggregate query = TypeQL.parseQuery(queryStr).asGetGroupAggregate();
-        Context.Query context = new Context.Query(transactionSvc.context(), options.query(query), query);
-        FunctionalIterator<ValueGroup> answers = queryMgr.get(query, context);
-        transactionSvc.stream(answers, reqID, context.options(), a -> getGroupAggregateResPart(reqID, a));
+        SlowQueryLog slowQueryLog = slowQueryLog(queryStr, "get-group-aggregate", options);
+        try {
+            TypeQLGet.Group.Aggregate query = TypeQL.parseQuery(queryStr).asGetGroupAggregate();
+            slowQueryLog.parsed();
+            Context.Query context = new Context.Query(transactionSvc.context(), options.query(query), query);
+            FunctionalIterator<ValueGroup> answers = queryMgr.get(query, context);
+            slowQueryLog.prepared();
+            transactionSvc.stream(recordStream(slowQueryLog, answers), reqID, context.options(), a -> getGroupAggregateResPart(reqID, a));
+        } catch (RuntimeException e) {
+            slowQueryLog.finish(FAILED);
+            throw e;
+        }
     }
 
     private void fetch(String queryStr, Options.Query options, UUID reqID) {
-        TypeQLFetch query = TypeQL.parseQuery(queryStr).asFetch();
-        Context.Query context = new Context.Query(transactionSvc.context(), options.query(query), query);
-        FunctionalIterator<ReadableConceptTree> answers = queryMgr.fetch(query, context);
-        transactionSvc.stream(answers, reqID, context.options(), a -> fetchResPart(reqID, a));
+        SlowQueryLog slowQueryLog = slowQueryLog(queryStr, "fetch", options);
+        try {
+            TypeQLFetch query = TypeQL.parseQuery(queryStr).asFetch();
+            slowQueryLog.parsed();
+            Context.Query context = new Context.Query(transactionSvc.context(), options.query(query), query);
+            FunctionalIterator<ReadableConceptTree> answers = queryMgr.fetch(query, context);
+            slowQueryLog.prepared();
+            transactionSvc.stream(recordStream(slowQueryLog, answers), reqID, context.options(), a -> fetchResPart(reqID, a));
+        } catch (RuntimeException e) {
+            slowQueryLog.finish(FAILED);
+            throw e;
+        }
     }
 
     private void insert(String queryStr, Options.Query options, UUID reqID) {
-        TypeQLInsert query = TypeQL.parseQuery(queryStr).asInsert();
-        Context.Query context = new Context.Query(transactionSvc.context(), options.query(query), query);
-        FunctionalIterator<ConceptMap> answers = queryMgr.insert(query, context);
-        transactionSvc.stream(answers, reqID, context.options(), a -> insertResPart(reqID, a));
+        SlowQueryLog slowQueryLog = slowQueryLog(queryStr, "insert", options);
+        try {
+            TypeQLInsert query = TypeQL.parseQuery(queryStr).asInsert();
+            slowQueryLog.parsed();
+            Context.Query context = new Context.Query(transactionSvc.context(), options.query(query), query);
+            FunctionalIterator<ConceptMap> answers = queryMgr.insert(query, context);
+            slowQueryLog.prepared();
+            transactionSvc.stream(recordStream(slowQueryLog, answers), reqID, context.options(), a -> insertResPart(reqID, a));
+        } catch (RuntimeException e) {
+            slowQueryLog.finish(FAILED);
+            throw e;
+        }
     }
 
     private void delete(String queryStr, Options.Query options, UUID reqID) {
-        TypeQLDelete query = TypeQL.parseQuery(queryStr).asDelete();
-        Context.Query context = new Context.Query(transactionSvc.context(), options.query(query), query);
-        queryMgr.delete(query, context);
+        SlowQueryLog slowQueryLog = slowQueryLog(queryStr, "delete", options);
+        try {
+            TypeQLDelete query = TypeQL.parseQuery(queryStr).asDelete();
+            slowQueryLog.parsed();
+            Context.Query context = new Context.Query(transactionSvc.context(), options.query(query), query);
+            queryMgr.delete(query, context);
+            slowQueryLog.finish(COMPLETED);
+        } finally {
+            slowQueryLog.finish(FAILED);
+        }
         transactionSvc.respond(deleteRes(reqID));
     }
 
     private void update(String queryStr, Options.Query options, UUID reqID) {
-        TypeQLUpdate query = TypeQL.parseQuery(queryStr).asUpdate();
-        Context.Query context = new Context.Query(transactionSvc.context(), options.query(query), query);
-        FunctionalIterator<ConceptMap> answers = queryMgr.update(query, context);
-        transactionSvc.stream(answers, reqID, context.options(), a -> updateResPart(reqID, a));
+        SlowQueryLog slowQueryLog = slowQueryLog(queryStr, "update", options);
+        try {
+            TypeQLUpdate query = TypeQL.parseQuery(queryStr).asUpdate();
+            slowQueryLog.parsed();
+            Context.Query context = new Context.Query(transactionSvc.context(), options.query(query), query);
+            FunctionalIterator<ConceptMap> answers = queryMgr.update(query, context);
+            slowQueryLog.prepared();
+            transactionSvc.stream(recordStream(slowQueryLog, answers), reqID, context.options(), a -> updateResPart(reqID, a));
+        } catch (RuntimeException e) {
+            slowQueryLog.finish(FAILED);
+            throw e;
+        }
+    }
+
+    private SlowQueryLog slowQueryLog(String queryStr, String queryType, Options.Query options) {
+        return new SlowQueryLog(queryStr, queryType, transactionSvc.databaseName(), options);
+    }
+
+    private <T> FunctionalIterator<T> recordStream(SlowQueryLog slowQueryLog, FunctionalIterator<T> answers) {
+        FunctionalIterator<T> recorded = slowQueryLog.record(answers, streamingSlowQueryLogs::remove);
+        if (recorded != answers) streamingSlowQueryLogs.add(slowQueryLog);
+        return recorded;
+    }
+
+    public void close() {
+        streamingSlowQueryLogs.forEach(SlowQueryLog::abandon);
+        streamingSlowQueryLogs.clear();
     }
 
     private void explain(long explainableId, UUID reqID) {
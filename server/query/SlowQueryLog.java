@@ -0,0 +1,179 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+package com.vaticle.typedb.core.server.query;
+
+import com.eclipsesource.json.Json;
+import com.eclipsesource.json.JsonObject;
+import com.vaticle.typedb.core.common.iterator.AbstractFunctionalIterator;
+import com.vaticle.typedb.core.common.iterator.FunctionalIterator;
+import com.vaticle.typedb.core.common.parameters.Options;
+import org.slf4j.Logger;
+import org.slf4j.LoggerFactory;
+
+import javax.annotation.Nullable;
+import java.time.Duration;
+import java.time.Instant;
+import java.util.concurrent.ThreadLocalRandom;
+import java.util.concurrent.atomic.AtomicBoolean;
+import java.util.concurrent.atomic.AtomicLong;
+import java.util.function.Consumer;
+
+import static java.util.concurrent.TimeUnit.NANOSECONDS;
+
+/**
+ * Records the lifecycle of a single query and, if it took longer than the configured threshold,
+ * logs it as a structured JSON line. Timings are split into the parse, prepare (type inference)
+ * and execute phases, and the execute phase is further split into the time the server spent producing answers
+ * and the time it spent waiting for the client to request more of them.
+ *
+ * A query is logged once, with the outcome of whichever happens first: it completes, it fails, or, for a streamed
+ * query, its transaction closes before the answers are fully consumed.
+ *
+ * The traversal plan is not included: the reasoner plans each conjunction lazily and may re-plan it while answers
+ * are produced, so there is no single plan for the query at this layer.
+ */
+class SlowQueryLog {
+
+    private static final Logger LOG = LoggerFactory.getLogger(SlowQueryLog.class);
+
+    enum Outcome {
+        COMPLETED("completed"),
+        FAILED("failed"),
+        ABANDONED("abandoned");
+
+        private final String name;
+
+        Outcome(String name) {
+            this.name = name;
+        }
+    }
+
+    private final String query;
+    private final String queryType;
+    private final String database;
+    private final Options.Query options;
+    private final Instant start;
+    private final AtomicLong answerCount;
+    private final AtomicLong productionNanos;
+    private final AtomicBoolean isFinished;
+    private volatile Instant parsed;
+    private volatile Instant prepared;
+    private volatile boolean isStreamed;
+
+    SlowQueryLog(String query, String queryType, @Nullable String database, Options.Query options) {
+        this.query = query;
+        this.queryType = queryType;
+        this.database = database;
+        this.options = options;
+        this.start = Instant.now();
+        this.answerCount = new AtomicLong(0);
+        this.productionNanos = new AtomicLong(0);
+        this.isFinished = new AtomicBoolean(false);
+    }
+
+    void parsed() {
+        parsed = Instant.now();
+    }
+
+    void prepared() {
+        prepared = Instant.now();
+    }
+
+    <T> FunctionalIterator<T> record(FunctionalIterator<T> answers, Consumer<SlowQueryLog> onFinish) {
+        if (!options.slowQueryLog()) return answers;
+        isStreamed = true;
+        return new Recorded<>(answers, () -> {
+            finish(Outcome.FAILED);
+            onFinish.accept(this);
+        }).onConsumed(() -> {
+            finish(Outcome.COMPLETED);
+            onFinish.accept(this);
+        });
+    }
+
+    void abandon() {
+        finish(Outcome.ABANDONED);
+    }
+
+    /**
+     * Logs the query with the given outcome if it was slow. Only the first outcome of a query is logged,
+     * so later calls have no effect.
+     */
+    void finish(Outcome outcome) {
+        if (!options.slowQueryLog() || !isFinished.compareAndSet(false, true)) return;
+        Instant end = Instant.now();
+        long totalMillis = Duration.between(start, end).toMillis();
+        if (totalMillis < options.slowQueryThresholdMillis()) return;
+        if (ThreadLocalRandom.current().nextDouble() >= options.slowQuerySamplingRate()) return;
+        LOG.warn(toJSON(end, totalMillis, outcome).toString());
+    }
+
+    private JsonObject toJSON(Instant end, long totalMillis, Outcome outcome) {
+        Instant parseEnd = parsed != null ? parsed : start;
+        Instant prepareEnd = prepared != null ? prepared : parseEnd;
+        long executeMillis = Duration.between(prepareEnd, end).toMillis();
+        long producingMillis = isStreamed ? NANOSECONDS.toMillis(productionNanos.get()) : executeMillis;
+        JsonObject timings = Json.object()
+                .add("parse", Duration.between(start, parseEnd).toMillis())
+                .add("prepare", Duration.between(parseEnd, prepareEnd).toMillis())
+                .add("execute", executeMillis)
+                .add("execute-producing", producingMillis)
+                .add("execute-awaiting-client", Math.max(0, executeMillis - producingMillis));
+        return Json.object()
+                .add("database", database)
+                .add("type", queryType)
+                .add("query", query)
+                .add("outcome", outcome.name)
+                .add("duration-millis", totalMillis)
+                .add("timings-millis", timings)
+                .add("answers", answerCount.get());
+    }
+
+    private class Recorded<T> extends AbstractFunctionalIterator<T> {
+
+        private final FunctionalIterator<T> answers;
+        private final Runnable onFailure;
+
+        private Recorded(FunctionalIterator<T> answers, Runnable onFailure) {
+            this.answers = answers;
+            this.onFailure = onFailure;
+        }
+
+        @Override
+        public boolean hasNext() {
+            long start = System.nanoTime();
+            try {
+                return answers.hasNext();
+            } catch (RuntimeException e) {
+                onFailure.run();
+                throw e;
+            } finally {
+                productionNanos.addAndGet(System.nanoTime() - start);
+            }
+        }
+
+        @Override
+        public T next() {
+            long start = System.nanoTime();
+            try {
+                T answer = answers.next();
+                answerCount.incrementAndGet();
+                return answer;
+            } catch (RuntimeException e) {
+                onFailure.run();
+                throw e;
+            } finally {
+                productionNanos.addAndGet(System.nanoTime() - start);
+            }
+        }
+
+        @Override
+        public void recycle() {
+            answers.recycle();
+        }
+    }
+}
@@ -0,0 +1,85 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+package com.vaticle.typedb.core.server.logging;
+
+import ch.qos.logback.classic.Level;
+import ch.qos.logback.classic.Logger;
+import ch.qos.logback.classic.spi.ILoggingEvent;
+import ch.qos.logback.core.read.ListAppender;
+import com.eclipsesource.json.Json;
+import com.eclipsesource.json.JsonObject;
+import org.junit.After;
+import org.junit.Before;
+import org.junit.Test;
+import org.slf4j.LoggerFactory;
+
+import static com.vaticle.typedb.common.collection.Collections.list;
+import static org.junit.Assert.assertEquals;
+import static org.junit.Assert.assertFalse;
+import static org.junit.Assert.assertNull;
+import static org.junit.Assert.assertTrue;
+
+public class AuditLogTest {
+
+    private Logger logger;
+    private ListAppender<ILoggingEvent> appender;
+
+    @Before
+    public void setUp() {
+        logger = (Logger) LoggerFactory.getLogger(AuditLog.class);
+        logger.setLevel(Level.INFO);
+        appender = new ListAppender<>();
+        appender.start();
+        logger.addAppender(appender);
+    }
+
+    @After
+    public void tearDown() {
+        logger.detachAppender(appender);
+        appender.stop();
+    }
+
+    @Test
+    public void database_creation_is_recorded() {
+        AuditLog.databaseCreated("social", null);
+        JsonObject entry = lastEntry();
+        assertEquals("database-create", entry.getString("operation", null));
+        assertEquals("social", entry.getString("database", null));
+        assertTrue(entry.getBoolean("success", false));
+        assertNull(entry.get("error"));
+    }
+
+    @Test
+    public void failed_database_deletion_is_recorded_with_error() {
+        AuditLog.databaseDeleted("social", new RuntimeException("database is in use"));
+        JsonObject entry = lastEntry();
+        assertEquals("database-delete", entry.getString("operation", null));
+        assertFalse(entry.getBoolean("success", true));
+        assertEquals("database is in use", entry.getString("error", null));
+    }
+
+    @Test
+    public void schema_commit_lists_queries_and_concept_operations() {
+        AuditLog.schemaCommitted(
+                "social", list("define person sub entity;"), list("label: \"person\" thing_type_delete_req {}"), null
+        );
+        JsonObject entry = lastEntry();
+        assertEquals("schema-commit", entry.getString("operation", null));
+        assertTrue(entry.getBoolean("success", false));
+        assertEquals(1, entry.get("queries").asArray().size());
+        assertEquals("define person sub entity;", entry.get("queries").asArray().get(0).asString());
+        assertEquals(1, entry.get("concept-operations").asArray().size());
+        assertEquals("label: \"person\" thing_type_delete_req {}", entry.get("concept-operations").asArray().get(0).asString());
+    }
+
+    private JsonObject lastEntry() {
+        assertFalse(appender.list.isEmpty());
+        ILoggingEvent event = appender.list.get(appender.list.size() - 1);
+        assertEquals(Level.INFO, event.getLevel());
+        return Json.parse(event.getFormattedMessage()).asObject();
+    }
+}
@@ -40,8 +40,13 @@ public class CoreConfigTest {
         CoreConfig config = CoreConfigFactory.config(CONFIG_PATH_DEFAULT, emptySet(), new CoreConfigParser());
         assertTrue(config.storage().dataDir().toString().endsWith("server/data"));
         assertEquals(new InetSocketAddress("0.0.0.0", 1729), config.server().address());
+        assertFalse(config.server().encryption().enabled());
+        assertEquals(0, config.server().maxConcurrentTransactions());
+        assertEquals(0, config.server().maxConcurrentTransactionsPerSession());
         assertEquals(500 * Bytes.MB, config.storage().databaseCache().dataSize());
         assertEquals(500 * Bytes.MB, config.storage().databaseCache().indexSize());
+        assertEquals(0, config.storage().readaheadSize());
+        assertEquals(0, config.storage().compactionRateLimit());
         assertFalse(config.vaticleFactory().enabled());
         assertTrue(config.log().output().outputs().containsKey("stdout"));
         assertTrue(config.log().output().outputs().containsKey("file"));
@@ -53,6 +58,8 @@ public class CoreConfigTest {
         assertEquals("warn", config.log().logger().defaultLogger().level());
         assertFalse(config.log().debugger().reasonerTracer().enabled());
         assertFalse(config.log().debugger().reasonerPerfCounters().enabled());
+        assertFalse(config.log().slowQuery().enabled());
+        assertEquals(5000, config.log().slowQuery().thresholdMillis());
         assertTrue(config.developmentMode().enabled());
     }
 
@@ -196,6 +203,155 @@ public class CoreConfigTest {
         assertEquals(set("stdout", "file"), set(configWithRepeatedArgs.log().logger().filteredLoggers().get("typedb").outputs()));
     }
 
+    @Test
+    public void slow_query_log_disabled_implicitly() {
+        Path configMinimalAbsPaths = Paths.get("./server/test/parameters/config/config-minimal-abs-path.yml");
+        CoreConfig config = CoreConfigFactory.config(configMinimalAbsPaths, new HashSet<>(), new CoreConfigParser());
+        assertFalse(config.log().slowQuery().enabled());
+    }
+
+    @Test
+    public void slow_query_log_accepts_overrides() {
+        CoreConfig config = CoreConfigFactory.config(
+                CONFIG_PATH_DEFAULT,
+                set(
+                        new Option("log.slow-query.enable", "true"),
+                        new Option("log.slow-query.threshold", "2s"),
+                        new Option("log.slow-query.sampling-rate", "0.5")
+                ),
+                new CoreConfigParser()
+        );
+        assertTrue(config.log().slowQuery().enabled());
+        assertEquals(2000, config.log().slowQuery().thresholdMillis());
+        assertEquals(0.5, config.log().slowQuery().samplingRate(), 0.0);
+    }
+
+    @Test
+    public void slow_query_log_accepts_sub_second_threshold() {
+        CoreConfig config = CoreConfigFactory.config(
+                CONFIG_PATH_DEFAULT,
+                set(new Option("log.slow-query.threshold", "250ms")),
+                new CoreConfigParser()
+        );
+        assertEquals(250, config.log().slowQuery().thresholdMillis());
+    }
+
+    @Test
+    public void slow_query_log_accepts_integer_sampling_rate() {
+        CoreConfig config = CoreConfigFactory.config(
+                CONFIG_PATH_DEFAULT,
+                set(new Option("log.slow-query.sampling-rate", "0")),
+                new CoreConfigParser()
+        );
+        assertEquals(0.0, config.log().slowQuery().samplingRate(), 0.0);
+    }
+
+    @Test
+    public void slow_query_log_invalid_sampling_rate_throws() {
+        try {
+            CoreConfigFactory.config(
+                    CONFIG_PATH_DEFAULT,
+                    set(new Option("log.slow-query.sampling-rate", "1.5")),
+                    new CoreConfigParser()
+            );
+            fail();
+        } catch (TypeDBException e) {
+            assertEquals(CONFIG_VALUE_UNEXPECTED.code(), e.errorMessage().code());
+        }
+    }
+
+    @Test
+    public void encryption_disabled_implicitly() {
+        Path configMinimalAbsPaths = Paths.get("./server/test/parameters/config/config-minimal-abs-path.yml");
+        CoreConfig config = CoreConfigFactory.config(configMinimalAbsPaths, new HashSet<>(), new CoreConfigParser());
+        assertFalse(config.server().encryption().enabled());
+    }
+
+    @Test
+    public void encryption_accepts_overrides() {
+        CoreConfig config = CoreConfigFactory.config(
+                CONFIG_PATH_DEFAULT,
+                set(
+                        new Option("server.encryption.enable", "true"),
+                        new Option("server.encryption.certificate", "/tls/server.crt"),
+                        new Option("server.encryption.private-key", "/tls/server.key")
+                ),
+                new CoreConfigParser()
+        );
+        assertTrue(config.server().encryption().enabled());
+        assertEquals(Paths.get("/tls/server.crt"), config.server().encryption().certificate());
+        assertEquals(Paths.get("/tls/server.key"), config.server().encryption().privateKey());
+        assertFalse(config.server().encryption().rootCA().isPresent());
+    }
+
+    @Test
+    public void encryption_enabled_without_certificate_throws() {
+        try {
+            CoreConfigFactory.config(
+                    CONFIG_PATH_DEFAULT,
+                    set(new Option("server.encryption.enable", "true")),
+                    new CoreConfigParser()
+            );
+            fail();
+        } catch (TypeDBException e) {
+            assertEquals(CONFIG_KEY_MISSING.code(), e.errorMessage().code());
+        }
+    }
+
+    @Test
+    public void max_concurrent_transactions_accepts_override() {
+        CoreConfig config = CoreConfigFactory.config(
+                CONFIG_PATH_DEFAULT,
+                set(new Option("server.max-concurrent-transactions", "64")),
+                new CoreConfigParser()
+        );
+        assertEquals(64, config.server().maxConcurrentTransactions());
+    }
+
+    @Test
+    public void max_concurrent_transactions_per_session_accepts_override() {
+        CoreConfig config = CoreConfigFactory.config(
+                CONFIG_PATH_DEFAULT,
+                set(new Option("server.max-concurrent-transactions-per-session", "8")),
+                new CoreConfigParser()
+        );
+        assertEquals(8, config.server().maxConcurrentTransactionsPerSession());
+    }
+
+    @Test
+    public void max_concurrent_transactions_negative_throws() {
+        try {
+            CoreConfigFactory.config(
+                    CONFIG_PATH_DEFAULT,
+                    set(new Option("server.max-concurrent-transactions", "-1")),
+                    new CoreConfigParser()
+            );
+            fail();
+        } catch (TypeDBException e) {
+            assertEquals(CONFIG_VALUE_UNEXPECTED.code(), e.errorMessage().code());
+        }
+    }
+
+    @Test
+    public void storage_readahead_accepts_override() {
+        CoreConfig config = CoreConfigFactory.config(
+                CONFIG_PATH_DEFAULT,
+                set(new Option("storage.readahead", "2mb")),
+                new CoreConfigParser()
+        );
+        assertEquals(2 * Bytes.MB, config.storage().readaheadSize());
+    }
+
+    @Test
+    public void storage_compaction_rate_limit_accepts_override() {
+        CoreConfig config = CoreConfigFactory.config(
+                CONFIG_PATH_DEFAULT,
+                set(new Option("storage.compaction-rate-limit", "64mb")),
+                new CoreConfigParser()
+        );
+        assertEquals(64 * Bytes.MB, config.storage().compactionRateLimit());
+    }
+
     @Test
     public void development_mode_disabled_explicitly() {
         Path configPaths = Paths.get("./server/test/parameters/config/config-disabled-development-mode-explicitly.yml");
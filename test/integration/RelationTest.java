@@ -0,0 +1,214 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+package com.vaticle.typedb.core.test.integration;
+
+import com.vaticle.typedb.core.TypeDB;
+import com.vaticle.typedb.core.common.collection.ByteArray;
+import com.vaticle.typedb.core.common.diagnostics.Diagnostics;
+import com.vaticle.typedb.core.common.parameters.Arguments;
+import com.vaticle.typedb.core.common.parameters.Options.Database;
+import com.vaticle.typedb.core.concept.ConceptManager.RolePlayerIndexRepair;
+import com.vaticle.typedb.core.concept.thing.Entity;
+import com.vaticle.typedb.core.concept.thing.Relation;
+import com.vaticle.typedb.core.concept.thing.Thing;
+import com.vaticle.typedb.core.concept.thing.impl.RelationImpl;
+import com.vaticle.typedb.core.concept.thing.impl.ThingImpl;
+import com.vaticle.typedb.core.concept.type.RelationType;
+import com.vaticle.typedb.core.concept.type.RoleType;
+import com.vaticle.typedb.core.database.CoreDatabaseManager;
+import com.vaticle.typedb.core.graph.vertex.ThingVertex;
+import com.vaticle.typedb.core.test.integration.util.Util;
+import com.vaticle.typeql.lang.TypeQL;
+import org.junit.Before;
+import org.junit.BeforeClass;
+import org.junit.Test;
+
+import java.io.IOException;
+import java.nio.file.Path;
+import java.nio.file.Paths;
+import java.util.List;
+
+import static com.vaticle.typedb.common.collection.Collections.list;
+import static com.vaticle.typedb.core.common.collection.Bytes.MB;
+import static com.vaticle.typedb.core.common.exception.ErrorMessage.Internal.ILLEGAL_ARGUMENT;
+import static com.vaticle.typedb.core.common.parameters.Concept.Existence.STORED;
+import static com.vaticle.typedb.core.common.parameters.Concept.Transitivity.EXPLICIT;
+import static com.vaticle.typedb.core.common.test.Util.assertThrowsTypeDBException;
+import static com.vaticle.typedb.core.encoding.Encoding.Edge.Thing.Base.PLAYING;
+import static com.vaticle.typedb.core.encoding.Encoding.Edge.Thing.Base.RELATING;
+import static com.vaticle.typedb.core.encoding.Encoding.Edge.Thing.Optimised.ROLEPLAYER;
+import static org.junit.Assert.assertEquals;
+import static org.junit.Assert.assertFalse;
+import static org.junit.Assert.assertNotEquals;
+import static org.junit.Assert.assertTrue;
+
+public class RelationTest {
+
+    private static final Path dataDir = Paths.get(System.getProperty("user.dir")).resolve("relation-test");
+    private static final Path logDir = dataDir.resolve("logs");
+    private static final Database options = new Database().dataDir(dataDir).reasonerDebuggerDir(logDir)
+            .storageDataCacheSize(MB).storageIndexCacheSize(MB);
+    private static final String database = "relation-test";
+
+    @BeforeClass
+    public static void beforeClass() {
+        Diagnostics.Noop.initialise();
+    }
+
+    @Before
+    public void setUp() throws IOException {
+        Util.resetDirectory(dataDir);
+        try (TypeDB.DatabaseManager typedb = CoreDatabaseManager.open(options)) {
+            typedb.create(database);
+            try (TypeDB.Session session = typedb.session(database, Arguments.Session.Type.SCHEMA);
+                 TypeDB.Transaction tx = session.transaction(Arguments.Transaction.Type.WRITE)) {
+                tx.query().define(TypeQL.parseQuery("define " +
+                        "person sub entity, plays employment:employee, plays contract-employment:contractor, " +
+                        "    plays friendship:friend; " +
+                        "company sub entity, plays employment:employer; " +
+                        "employment sub relation, relates employee, relates employer; " +
+                        "contract-employment sub employment, relates contractor as employee; " +
+                        "friendship sub relation, relates friend;").asDefine());
+                tx.commit();
+            }
+            try (TypeDB.Session session = typedb.session(database, Arguments.Session.Type.DATA);
+                 TypeDB.Transaction tx = session.transaction(Arguments.Transaction.Type.WRITE)) {
+                tx.query().insert(TypeQL.parseQuery("insert " +
+                        "$p isa person; $c isa company; " +
+                        "(employee: $p, employer: $c) isa employment; " +
+                        "(contractor: $p, employer: $c) isa contract-employment; " +
+                        "(friend: $p) isa friendship;").asInsert()).toList();
+                tx.commit();
+            }
+        }
+    }
+
+    @Test
+    public void relations_by_relation_and_role_type_include_subtypes_of_both() {
+        try (TypeDB.DatabaseManager typedb = CoreDatabaseManager.open(options);
+             TypeDB.Session session = typedb.session(database, Arguments.Session.Type.DATA);
+             TypeDB.Transaction tx = session.transaction(Arguments.Transaction.Type.READ)) {
+            Entity person = tx.concepts().getEntityType("person").getInstances().first().get();
+            RelationType employment = tx.concepts().getRelationType("employment");
+            RelationType contractEmployment = tx.concepts().getRelationType("contract-employment");
+            RelationType friendship = tx.concepts().getRelationType("friendship");
+            RoleType employee = employment.getRelates("employee");
+            RoleType contractor = contractEmployment.getRelates("contractor");
+
+            List<? extends Relation> employments = person.getRelations(employment, employee).toList();
+            assertEquals(2, employments.size());
+            assertTrue(employments.stream().noneMatch(r -> r.getType().equals(friendship)));
+
+            List<? extends Relation> contractEmployments = person.getRelations(contractEmployment, employee).toList();
+            assertEquals(1, contractEmployments.size());
+            assertEquals(contractEmployment, contractEmployments.get(0).getType());
+
+            List<? extends Relation> contractors = person.getRelations(employment, contractor).toList();
+            assertEquals(1, contractors.size());
+            assertEquals(contractEmployment, contractors.get(0).getType());
+
+            assertEquals(0, person.getRelations(friendship, employee).count());
+        }
+    }
+
+    @Test
+    public void role_player_index_repair_restores_missing_and_removes_dangling_edges() {
+        try (TypeDB.DatabaseManager typedb = CoreDatabaseManager.open(options);
+             TypeDB.Session session = typedb.session(database, Arguments.Session.Type.DATA);
+             TypeDB.Transaction tx = session.transaction(Arguments.Transaction.Type.WRITE)) {
+            RelationType employmentType = tx.concepts().getRelationType("employment");
+            RelationImpl employment = (RelationImpl) employmentType.getInstances(EXPLICIT).first().get();
+            ThingVertex.Write relation = employment.readableVertex().toWrite();
+            ThingVertex person = vertex(tx.concepts().getEntityType("person").getInstances().first().get());
+            ThingVertex company = vertex(tx.concepts().getEntityType("company").getInstances().first().get());
+            ThingVertex employeeRole = roleOf(relation, person);
+            assertTrue(employment.isRolePlayerIndexConsistent());
+
+            relation.outs().edge(ROLEPLAYER, person, employeeRole).delete();
+            relation.outs().put(ROLEPLAYER, company.toWrite(), employeeRole.toWrite(), STORED);
+            assertFalse(employment.isRolePlayerIndexConsistent());
+
+            RolePlayerIndexRepair repair = tx.concepts().repairRolePlayerIndex(employmentType, null, 10);
+            assertEquals(1, repair.repaired());
+            assertTrue(repair.orphanedRoles().isEmpty());
+            assertFalse(repair.resumeAfter().isPresent());
+            assertTrue(employment.isRolePlayerIndexConsistent());
+            assertTrue(relation.outs().edge(ROLEPLAYER, person, employeeRole) != null);
+            assertTrue(relation.outs().edge(ROLEPLAYER, company, employeeRole) == null);
+            assertEquals(2, employment.getPlayers().count());
+        }
+    }
+
+    @Test
+    public void role_player_index_repair_reports_roles_without_players() {
+        try (TypeDB.DatabaseManager typedb = CoreDatabaseManager.open(options);
+             TypeDB.Session session = typedb.session(database, Arguments.Session.Type.DATA);
+             TypeDB.Transaction tx = session.transaction(Arguments.Transaction.Type.WRITE)) {
+            RelationType friendshipType = tx.concepts().getRelationType("friendship");
+            RelationImpl friendship = (RelationImpl) friendshipType.getInstances().first().get();
+            ThingVertex.Write relation = friendship.readableVertex().toWrite();
+            ThingVertex.Write person = vertex(tx.concepts().getEntityType("person").getInstances().first().get()).toWrite();
+            ThingVertex friendRole = roleOf(relation, person);
+
+            person.outs().edge(PLAYING, friendRole).delete();
+            assertEquals(list(friendRole.iid().bytes()), friendship.getOrphanedRoles());
+
+            RolePlayerIndexRepair repair = tx.concepts().repairRolePlayerIndex(friendshipType, null, 10);
+            assertEquals(1, repair.repaired());
+            assertEquals(list(friendRole.iid().bytes()), repair.orphanedRoles());
+            assertTrue(friendship.isRolePlayerIndexConsistent());
+        }
+    }
+
+    @Test
+    public void role_player_index_repair_resumes_in_batches() {
+        try (TypeDB.DatabaseManager typedb = CoreDatabaseManager.open(options);
+             TypeDB.Session session = typedb.session(database, Arguments.Session.Type.DATA);
+             TypeDB.Transaction tx = session.transaction(Arguments.Transaction.Type.WRITE)) {
+            RelationType employmentType = tx.concepts().getRelationType("employment");
+            List<? extends Relation> employments = employmentType.getInstances().toList();
+            assertEquals(2, employments.size());
+            ThingVertex.Write relation = vertex(employments.get(1)).toWrite();
+            ThingVertex person = vertex(tx.concepts().getEntityType("person").getInstances().first().get());
+            relation.outs().edge(ROLEPLAYER, person, roleOf(relation, person)).delete();
+
+            RolePlayerIndexRepair first = tx.concepts().repairRolePlayerIndex(employmentType, null, 1);
+            assertEquals(0, first.repaired());
+            ByteArray firstVisited = first.resumeAfter().get();
+            assertEquals(employments.get(0).getIID(), firstVisited);
+
+            RolePlayerIndexRepair second = tx.concepts().repairRolePlayerIndex(employmentType, firstVisited, 1);
+            assertEquals(1, second.repaired());
+            ByteArray secondVisited = second.resumeAfter().get();
+            assertNotEquals(firstVisited, secondVisited);
+
+            RolePlayerIndexRepair third = tx.concepts().repairRolePlayerIndex(employmentType, secondVisited, 1);
+            assertEquals(0, third.repaired());
+            assertFalse(third.resumeAfter().isPresent());
+        }
+    }
+
+    @Test
+    public void role_player_index_repair_rejects_non_positive_batch_size() {
+        try (TypeDB.DatabaseManager typedb = CoreDatabaseManager.open(options);
+             TypeDB.Session session = typedb.session(database, Arguments.Session.Type.DATA);
+             TypeDB.Transaction tx = session.transaction(Arguments.Transaction.Type.WRITE)) {
+            RelationType employmentType = tx.concepts().getRelationType("employment");
+            assertThrowsTypeDBException(
+                    () -> tx.concepts().repairRolePlayerIndex(employmentType, null, 0), ILLEGAL_ARGUMENT.code()
+            );
+        }
+    }
+
+    private static ThingVertex vertex(Thing thing) {
+        return ((ThingImpl) thing).readableVertex();
+    }
+
+    private static ThingVertex roleOf(ThingVertex relation, ThingVertex player) {
+        return relation.outs().edge(RELATING).to().filter(role -> role.ins().edge(PLAYING, player) != null).first().get();
+    }
+}
@@ -6,20 +6,33 @@
 
 package com.vaticle.typedb.core.database;
 
+import com.vaticle.typedb.core.TypeDB;
 import com.vaticle.typedb.core.common.diagnostics.Diagnostics;
+import com.vaticle.typedb.core.common.parameters.Arguments;
 import com.vaticle.typedb.core.common.parameters.Options;
 import com.vaticle.typedb.core.encoding.Encoding;
+import com.vaticle.typedb.core.encoding.key.Key;
+import com.vaticle.typeql.lang.TypeQL;
 import org.junit.BeforeClass;
 import org.junit.Test;
+import org.rocksdb.ColumnFamilyHandle;
+import org.rocksdb.FlushOptions;
+import org.rocksdb.RocksDBException;
+import org.rocksdb.TableProperties;
 
 import java.io.IOException;
 import java.nio.file.Files;
 import java.nio.file.Path;
 import java.nio.file.Paths;
+import java.util.List;
 
 import static com.vaticle.typedb.core.common.collection.Bytes.MB;
+import static com.vaticle.typedb.core.common.exception.ErrorMessage.Database.DATABASE_CLOSED;
 import static com.vaticle.typedb.core.common.exception.ErrorMessage.Database.INCOMPATIBLE_ENCODING;
+import static com.vaticle.typedb.core.common.iterator.Iterators.iterate;
+import static com.vaticle.typedb.core.common.test.Util.assertThrowsTypeDBException;
 import static com.vaticle.typedb.core.common.test.Util.assertThrowsWithMessage;
+import static org.junit.Assert.assertTrue;
 
 public class DatabaseTest {
 
@@ -41,6 +54,51 @@ public class DatabaseTest {
         databaseMgr.close();
     }
 
+    @Test
+    public void compactionRemovesDeletionTombstones() throws IOException, RocksDBException {
+        Path dataDir = Files.createTempDirectory("test-dir");
+        Path logDir = dataDir.resolve("logs");
+        Options.Database options = new Options.Database().dataDir(dataDir).reasonerDebuggerDir(logDir)
+                .storageIndexCacheSize(MB).storageDataCacheSize(MB).storageCompactionRateLimit(MB);
+        CoreDatabaseManager databaseMgr = factory.databaseManager(options);
+        CoreDatabase database = databaseMgr.create("test");
+        try (TypeDB.Session session = databaseMgr.session("test", Arguments.Session.Type.SCHEMA);
+             TypeDB.Transaction tx = session.transaction(Arguments.Transaction.Type.WRITE)) {
+            tx.query().define(TypeQL.parseQuery("define person sub entity;").asDefine());
+            tx.commit();
+        }
+        try (TypeDB.Session session = databaseMgr.session("test", Arguments.Session.Type.DATA)) {
+            try (TypeDB.Transaction tx = session.transaction(Arguments.Transaction.Type.WRITE)) {
+                for (int i = 0; i < 1000; i++) tx.query().insert(TypeQL.parseQuery("insert $p isa person;").asInsert()).toList();
+                tx.commit();
+            }
+            flush(database);
+            try (TypeDB.Transaction tx = session.transaction(Arguments.Transaction.Type.WRITE)) {
+                tx.query().delete(TypeQL.parseQuery("match $p isa person; delete $p isa person;").asDelete());
+                tx.commit();
+            }
+            flush(database);
+        }
+        long deletions = numDeletions(database);
+        assertTrue(deletions > 0);
+        database.compact();
+        assertTrue(numDeletions(database) < deletions);
+        databaseMgr.close();
+    }
+
+    @Test
+    public void compactionOnClosedDatabaseThrows() throws IOException {
+        Path dataDir = Files.createTempDirectory("test-dir");
+        Path logDir = dataDir.resolve("logs");
+        Options.Database options = new Options.Database().dataDir(dataDir).reasonerDebuggerDir(logDir)
+                .storageIndexCacheSize(MB).storageDataCacheSize(MB);
+        CoreDatabaseManager databaseMgr = factory.databaseManager(options);
+        CoreDatabase database = databaseMgr.create("test");
+        database.close();
+        assertThrowsTypeDBException(database::compact, DATABASE_CLOSED.code());
+        databaseMgr.close();
+    }
+
     @Test
     public void incompatibleDataEncodingThrows() {
         Path dataDir = Paths.get("test/integration/database/data");
@@ -52,4 +110,23 @@ public class DatabaseTest {
                 INCOMPATIBLE_ENCODING.message("test", dataDir.resolve("test").toAbsolutePath(), 0, Encoding.ENCODING_VERSION)
         );
     }
+
+    private static void flush(CoreDatabase database) throws RocksDBException {
+        List<ColumnFamilyHandle> partitions = iterate(database.rocksDataPartitionMgr.partitions())
+                .map(database.rocksDataPartitionMgr::get).toList();
+        try (FlushOptions flushOptions = new FlushOptions().setWaitForFlush(true)) {
+            database.rocksData.flush(flushOptions, partitions);
+        }
+    }
+
+    private static long numDeletions(CoreDatabase database) throws RocksDBException {
+        long deletions = 0;
+        for (Key.Partition partition : database.rocksDataPartitionMgr.partitions()) {
+            ColumnFamilyHandle handle = database.rocksDataPartitionMgr.get(partition);
+            for (TableProperties properties : database.rocksData.getPropertiesOfAllTables(handle).values()) {
+                deletions += properties.getNumDeletions();
+            }
+        }
+        return deletions;
+    }
 }
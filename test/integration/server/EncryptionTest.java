@@ -0,0 +1,140 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+package com.vaticle.typedb.core.server;
+
+import com.vaticle.typedb.core.common.exception.TypeDBCheckedException;
+import com.vaticle.typedb.core.server.parameters.CoreConfig;
+import com.vaticle.typedb.core.server.parameters.CoreConfigFactory;
+import com.vaticle.typedb.core.server.parameters.CoreConfigParser;
+import com.vaticle.typedb.core.server.parameters.util.Option;
+import com.vaticle.typedb.protocol.DatabaseProto.DatabaseManager;
+import com.vaticle.typedb.protocol.TypeDBGrpc;
+import io.grpc.ManagedChannel;
+import io.grpc.Status;
+import io.grpc.StatusRuntimeException;
+import io.grpc.netty.GrpcSslContexts;
+import io.grpc.netty.NettyChannelBuilder;
+import io.netty.handler.ssl.SslContextBuilder;
+import io.netty.handler.ssl.util.SelfSignedCertificate;
+import org.junit.After;
+import org.junit.Before;
+import org.junit.Test;
+
+import java.io.IOException;
+import java.net.ServerSocket;
+import java.nio.file.Files;
+import java.nio.file.Path;
+import java.nio.file.Paths;
+import java.security.cert.CertificateException;
+import java.util.HashSet;
+import java.util.Set;
+import java.util.concurrent.TimeUnit;
+
+import static com.vaticle.typedb.common.collection.Collections.set;
+import static com.vaticle.typedb.core.test.integration.util.Util.deleteDirectory;
+import static org.junit.Assert.assertEquals;
+import static org.junit.Assert.assertTrue;
+import static org.junit.Assert.fail;
+
+public class EncryptionTest {
+
+    private static final Path CONFIG_PATH_DEFAULT = Paths.get("server/parameters/config/config.yml");
+    private static final String HOST = "localhost";
+
+    private Path logDir;
+    private Path dataDir;
+    private SelfSignedCertificate serverCertificate;
+    private SelfSignedCertificate clientCertificate;
+    private TypeDBServer typeDBServer;
+    private int port;
+
+    @Before
+    public void setUp() throws IOException, CertificateException {
+        logDir = Files.createTempDirectory("log-file-tmp");
+        dataDir = Files.createTempDirectory("data-tmp");
+        serverCertificate = new SelfSignedCertificate(HOST);
+        clientCertificate = new SelfSignedCertificate(HOST);
+        try (ServerSocket socket = new ServerSocket(0)) {
+            port = socket.getLocalPort();
+        }
+    }
+
+    @After
+    public void tearDown() throws IOException, TypeDBCheckedException {
+        if (typeDBServer != null) typeDBServer.close();
+        serverCertificate.delete();
+        clientCertificate.delete();
+        deleteDirectory(dataDir);
+        deleteDirectory(logDir);
+    }
+
+    @Test
+    public void client_completes_handshake_with_encrypted_server() throws IOException, TypeDBCheckedException {
+        typeDBServer = startServer(new HashSet<>());
+        ManagedChannel channel = channel(GrpcSslContexts.forClient().trustManager(serverCertificate.certificate()));
+        try {
+            assertTrue(databasesAll(channel).getDatabasesList().isEmpty());
+        } finally {
+            channel.shutdownNow();
+        }
+    }
+
+    @Test
+    public void client_without_certificate_is_rejected_when_root_ca_is_set() throws IOException, TypeDBCheckedException {
+        typeDBServer = startServer(set(
+                new Option("server.encryption.root-ca", clientCertificate.certificate().getAbsolutePath())
+        ));
+        ManagedChannel channel = channel(GrpcSslContexts.forClient().trustManager(serverCertificate.certificate()));
+        try {
+            databasesAll(channel);
+            fail();
+        } catch (StatusRuntimeException e) {
+            assertEquals(Status.Code.UNAVAILABLE, e.getStatus().getCode());
+        } finally {
+            channel.shutdownNow();
+        }
+    }
+
+    @Test
+    public void client_with_certificate_is_accepted_when_root_ca_is_set() throws IOException, TypeDBCheckedException {
+        typeDBServer = startServer(set(
+                new Option("server.encryption.root-ca", clientCertificate.certificate().getAbsolutePath())
+        ));
+        ManagedChannel channel = channel(GrpcSslContexts.forClient().trustManager(serverCertificate.certificate())
+                .keyManager(clientCertificate.certificate(), clientCertificate.privateKey()));
+        try {
+            assertTrue(databasesAll(channel).getDatabasesList().isEmpty());
+        } finally {
+            channel.shutdownNow();
+        }
+    }
+
+    private TypeDBServer startServer(Set<Option> encryptionOptions) throws TypeDBCheckedException {
+        Set<Option> options = new HashSet<>(set(
+                new Option("server.address", HOST + ":" + port),
+                new Option("server.encryption.enable", "true"),
+                new Option("server.encryption.certificate", serverCertificate.certificate().getAbsolutePath()),
+                new Option("server.encryption.private-key", serverCertificate.privateKey().getAbsolutePath()),
+                new Option("storage.data", dataDir.toAbsolutePath().toString()),
+                new Option("log.output.file.base-dir", logDir.toAbsolutePath().toString())
+        ));
+        options.addAll(encryptionOptions);
+        CoreConfig config = CoreConfigFactory.config(CONFIG_PATH_DEFAULT, options, new CoreConfigParser());
+        TypeDBServer server = TypeDBServer.create(config, false);
+        server.start();
+        return server;
+    }
+
+    private ManagedChannel channel(SslContextBuilder sslContext) throws IOException {
+        return NettyChannelBuilder.forAddress(HOST, port).sslContext(sslContext.build()).build();
+    }
+
+    private DatabaseManager.All.Res databasesAll(ManagedChannel channel) {
+        return TypeDBGrpc.newBlockingStub(channel).withDeadlineAfter(10, TimeUnit.SECONDS)
+                .databasesAll(DatabaseManager.All.Req.newBuilder().build());
+    }
+}
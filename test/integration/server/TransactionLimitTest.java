@@ -0,0 +1,236 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+package com.vaticle.typedb.core.server;
+
+import com.google.protobuf.ByteString;
+import com.vaticle.typedb.core.common.exception.TypeDBCheckedException;
+import com.vaticle.typedb.core.server.parameters.CoreConfig;
+import com.vaticle.typedb.core.server.parameters.CoreConfigFactory;
+import com.vaticle.typedb.core.server.parameters.CoreConfigParser;
+import com.vaticle.typedb.core.server.parameters.util.Option;
+import com.vaticle.typedb.protocol.SessionProto;
+import com.vaticle.typedb.protocol.TransactionProto;
+import com.vaticle.typedb.protocol.TypeDBGrpc;
+import io.grpc.ManagedChannel;
+import io.grpc.Status;
+import io.grpc.StatusRuntimeException;
+import io.grpc.netty.NettyChannelBuilder;
+import io.grpc.stub.ClientCallStreamObserver;
+import io.grpc.stub.StreamObserver;
+import org.junit.After;
+import org.junit.Before;
+import org.junit.Test;
+
+import java.io.IOException;
+import java.net.ServerSocket;
+import java.nio.file.Files;
+import java.nio.file.Path;
+import java.nio.file.Paths;
+import java.util.UUID;
+import java.util.concurrent.BlockingQueue;
+import java.util.concurrent.LinkedBlockingQueue;
+import java.util.concurrent.TimeUnit;
+
+import static com.vaticle.typedb.common.collection.Collections.set;
+import static com.vaticle.typedb.core.server.common.ResponseBuilder.UUIDAsByteString;
+import static com.vaticle.typedb.core.test.integration.util.Util.deleteDirectory;
+import static org.junit.Assert.assertEquals;
+import static org.junit.Assert.assertNotNull;
+import static org.junit.Assert.assertTrue;
+
+public class TransactionLimitTest {
+
+    private static final Path CONFIG_PATH_DEFAULT = Paths.get("server/parameters/config/config.yml");
+    private static final String HOST = "localhost";
+    private static final String DATABASE = "limit-test";
+    private static final int INVALID_TRANSACTION_TYPE = 99;
+    private static final int CANCELLED_OPENS = 20;
+
+    private Path logDir;
+    private Path dataDir;
+    private TypeDBServer typeDBServer;
+    private ManagedChannel channel;
+    private int port;
+
+    @Before
+    public void setUp() throws IOException {
+        logDir = Files.createTempDirectory("log-file-tmp");
+        dataDir = Files.createTempDirectory("data-tmp");
+        try (ServerSocket socket = new ServerSocket(0)) {
+            port = socket.getLocalPort();
+        }
+    }
+
+    @After
+    public void tearDown() throws IOException, TypeDBCheckedException {
+        if (channel != null) channel.shutdownNow();
+        if (typeDBServer != null) typeDBServer.close();
+        deleteDirectory(dataDir);
+        deleteDirectory(logDir);
+    }
+
+    @Test
+    public void transactions_beyond_server_limit_are_rejected_until_one_closes() throws TypeDBCheckedException, InterruptedException {
+        startServer(2, 0);
+        ByteString session = openSession();
+
+        Transaction first = new Transaction();
+        assertOpened(first.open(session, TransactionProto.Transaction.Type.READ_VALUE));
+        Transaction second = new Transaction();
+        assertOpened(second.open(session, TransactionProto.Transaction.Type.READ_VALUE));
+        assertEquals(2, typeDBServer.typeDBService.openTransactions());
+
+        Transaction third = new Transaction();
+        assertRejected(third.open(session, TransactionProto.Transaction.Type.READ_VALUE), Status.Code.RESOURCE_EXHAUSTED);
+        assertEquals(2, typeDBServer.typeDBService.openTransactions());
+
+        first.close();
+        assertEquals(1, typeDBServer.typeDBService.openTransactions());
+        Transaction fourth = new Transaction();
+        assertOpened(fourth.open(session, TransactionProto.Transaction.Type.READ_VALUE));
+        assertEquals(2, typeDBServer.typeDBService.openTransactions());
+
+        second.close();
+        fourth.close();
+        assertEquals(0, typeDBServer.typeDBService.openTransactions());
+    }
+
+    @Test
+    public void failed_open_releases_transaction_slot() throws TypeDBCheckedException, InterruptedException {
+        startServer(1, 1);
+        ByteString session = openSession();
+
+        Transaction invalid = new Transaction();
+        assertRejected(invalid.open(session, INVALID_TRANSACTION_TYPE), Status.Code.INTERNAL);
+        assertEquals(0, typeDBServer.typeDBService.openTransactions());
+
+        Transaction valid = new Transaction();
+        assertOpened(valid.open(session, TransactionProto.Transaction.Type.READ_VALUE));
+        assertEquals(1, typeDBServer.typeDBService.openTransactions());
+        valid.close();
+    }
+
+    @Test
+    public void transactions_beyond_session_limit_are_rejected() throws TypeDBCheckedException, InterruptedException {
+        startServer(0, 1);
+        ByteString session = openSession();
+        ByteString otherSession = openSession();
+
+        Transaction first = new Transaction();
+        assertOpened(first.open(session, TransactionProto.Transaction.Type.READ_VALUE));
+        Transaction second = new Transaction();
+        assertRejected(second.open(session, TransactionProto.Transaction.Type.READ_VALUE), Status.Code.RESOURCE_EXHAUSTED);
+        Transaction other = new Transaction();
+        assertOpened(other.open(otherSession, TransactionProto.Transaction.Type.READ_VALUE));
+        assertEquals(2, typeDBServer.typeDBService.openTransactions());
+
+        first.close();
+        Transaction third = new Transaction();
+        assertOpened(third.open(session, TransactionProto.Transaction.Type.READ_VALUE));
+        third.close();
+        other.close();
+    }
+
+    @Test
+    public void transaction_cancelled_during_open_releases_transaction_slot() throws TypeDBCheckedException, InterruptedException {
+        startServer(1, 1);
+        ByteString session = openSession();
+
+        for (int i = 0; i < CANCELLED_OPENS; i++) new Transaction().openAndCancel(session);
+        long deadline = System.currentTimeMillis() + TimeUnit.SECONDS.toMillis(Transaction.TIMEOUT_SECONDS);
+        while (typeDBServer.typeDBService.openTransactions() > 0 && System.currentTimeMillis() < deadline) {
+            Thread.sleep(10);
+        }
+        assertEquals(0, typeDBServer.typeDBService.openTransactions());
+
+        Transaction valid = new Transaction();
+        assertOpened(valid.open(session, TransactionProto.Transaction.Type.READ_VALUE));
+        valid.close();
+    }
+
+    private void startServer(int transactionLimit, int sessionTransactionLimit) throws TypeDBCheckedException {
+        CoreConfig config = CoreConfigFactory.config(CONFIG_PATH_DEFAULT, set(
+                new Option("server.address", HOST + ":" + port),
+                new Option("server.max-concurrent-transactions", String.valueOf(transactionLimit)),
+                new Option("server.max-concurrent-transactions-per-session", String.valueOf(sessionTransactionLimit)),
+                new Option("storage.data", dataDir.toAbsolutePath().toString()),
+                new Option("log.output.file.base-dir", logDir.toAbsolutePath().toString())
+        ), new CoreConfigParser());
+        typeDBServer = TypeDBServer.create(config, false);
+        typeDBServer.start();
+        typeDBServer.databaseMgr.create(DATABASE);
+        channel = NettyChannelBuilder.forAddress(HOST, port).usePlaintext().build();
+    }
+
+    private ByteString openSession() {
+        return TypeDBGrpc.newBlockingStub(channel).sessionOpen(SessionProto.Session.Open.Req.newBuilder()
+                .setDatabase(DATABASE).setType(SessionProto.Session.Type.DATA).build()
+        ).getSessionId();
+    }
+
+    private static void assertOpened(Object response) {
+        assertTrue(response instanceof TransactionProto.Transaction.Server);
+        assertTrue(((TransactionProto.Transaction.Server) response).getRes().hasOpenRes());
+    }
+
+    private static void assertRejected(Object response, Status.Code code) {
+        assertTrue(response instanceof StatusRuntimeException);
+        assertEquals(code, ((StatusRuntimeException) response).getStatus().getCode());
+    }
+
+    private class Transaction {
+
+        private static final long TIMEOUT_SECONDS = 10;
+        private final BlockingQueue<Object> responses;
+        private final StreamObserver<TransactionProto.Transaction.Client> requests;
+
+        private Transaction() {
+            responses = new LinkedBlockingQueue<>();
+            requests = TypeDBGrpc.newStub(channel).transaction(new StreamObserver<>() {
+                @Override
+                public void onNext(TransactionProto.Transaction.Server response) {
+                    responses.add(response);
+                }
+
+                @Override
+                public void onError(Throwable error) {
+                    responses.add(error);
+                }
+
+                @Override
+                public void onCompleted() {
+                    responses.add(Boolean.TRUE);
+                }
+            });
+        }
+
+        private Object open(ByteString session, int type) throws InterruptedException {
+            requests.onNext(openReq(session, type));
+            Object response = responses.poll(TIMEOUT_SECONDS, TimeUnit.SECONDS);
+            assertNotNull(response);
+            return response;
+        }
+
+        private void openAndCancel(ByteString session) {
+            requests.onNext(openReq(session, TransactionProto.Transaction.Type.READ_VALUE));
+            ((ClientCallStreamObserver<TransactionProto.Transaction.Client>) requests).cancel("cancelled during open", null);
+        }
+
+        private TransactionProto.Transaction.Client openReq(ByteString session, int type) {
+            return TransactionProto.Transaction.Client.newBuilder().addReqs(
+                    TransactionProto.Transaction.Req.newBuilder().setReqId(UUIDAsByteString(UUID.randomUUID()))
+                            .setOpenReq(TransactionProto.Transaction.Open.Req.newBuilder()
+                                    .setSessionId(session).setTypeValue(type))
+            ).build();
+        }
+
+        private void close() throws InterruptedException {
+            requests.onCompleted();
+            assertEquals(Boolean.TRUE, responses.poll(TIMEOUT_SECONDS, TimeUnit.SECONDS));
+        }
+    }
+}